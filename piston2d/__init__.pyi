@@ -1,15 +1,65 @@
-from typing import Callable, Tuple
+from typing import Any, Callable, List, Optional, Tuple, Union
+
+from .graphics import Context
+from .input import Button, Key, MouseButton
+from .opengl import GlGraphics
+from .window import WindowSettings
+from .window.events import EventSettings, RenderArgs, UpdateArgs
 
 __version__: str
 
+class Handler:
+    def __call__(self, *args: Any, **kwargs: Any) -> Any: ...
+    @property
+    def __wrapped__(self) -> Callable: ...
+    @property
+    def registered(self) -> bool: ...
+    def remove(self) -> bool: ...
+
+
 class Piston2dApp:
     def tick(self): ...
+    def run(self): ...
+    def stop(self): ...
+
+    def is_key_down(self, key: Union[Key, Button, int]) -> bool: ...
+    def keys_down(self) -> List[Key]: ...
+    def is_mouse_down(self, button: Union[MouseButton, Button, int]) -> bool: ...
+    def mouse_buttons_down(self) -> List[MouseButton]: ...
+    def cursor_position(self) -> Optional[Tuple[float, float]]: ...
+    def scroll_delta(self) -> Tuple[float, float]: ...
+    def was_pressed(self, button: Union[Button, Key, MouseButton, int]) -> bool: ...
+    def was_released(self, button: Union[Button, Key, MouseButton, int]) -> bool: ...
+
+    def render(self, callable: Callable[[Context, GlGraphics, RenderArgs], None]) -> Handler: ...
+    def update(self, callable: Callable[[UpdateArgs], None]) -> Handler: ...
 
-    def render(self, callable: Callable): ...
-    def update(self, callable: Callable): ...
+    def on_start(self, callable: Callable[[], None]) -> Handler: ...
+    def on_exit(self, callable: Callable[[], None]) -> Handler: ...
+    def on_render(self, callable: Callable[[Context, GlGraphics, RenderArgs], None]) -> Handler: ...
+    def on_update(self, callable: Callable[[UpdateArgs], None]) -> Handler: ...
+    def on_idle(self, callable: Callable[[float], None]) -> Handler: ...
+    def on_button_press(self, callable: Callable[[Button], None]) -> Handler: ...
+    def on_button_release(self, callable: Callable[[Button], None]) -> Handler: ...
+    def on_key_press(self, callable: Callable[[Button], None]) -> Handler: ...
+    def on_key_release(self, callable: Callable[[Button], None]) -> Handler: ...
+    def on_mouse_press(self, callable: Callable[[Button], None]) -> Handler: ...
+    def on_mouse_release(self, callable: Callable[[Button], None]) -> Handler: ...
+    def on_mouse_move(self, callable: Callable[[float, float], None]) -> Handler: ...
+    def on_mouse_relative(self, callable: Callable[[float, float], None]) -> Handler: ...
+    def on_scroll(self, callable: Callable[[float, float], None]) -> Handler: ...
+    def on_resize(self, callable: Callable[[float, float], None]) -> Handler: ...
+    def on_focus(self, callable: Callable[[bool], None]) -> Handler: ...
+    def on_cursor(self, callable: Callable[[bool], None]) -> Handler: ...
+    def on_text(self, callable: Callable[[str], None]) -> Handler: ...
+    def on_close(self, callable: Callable[[], None]) -> Handler: ...
+    def on_error(self, callable: Optional[Callable[[Exception], None]]) -> Optional[Callable[[Exception], None]]: ...
 
     def get_title(self) -> str: ...
     def set_title(self, title: str): ...
 
 
-def init(title: str, size: Tuple[int, int]) -> Piston2dApp: ...
+def init(title: Optional[str] = None, size: Optional[Tuple[int, int]] = None,
+         settings: Optional[WindowSettings] = None,
+         event_settings: Optional[EventSettings] = None,
+         opengl: Optional[str] = None) -> Piston2dApp: ...
@@ -0,0 +1,9 @@
+from .piston2d import errors
+
+__doc__ = errors.__doc__
+
+Piston2dError = errors.Piston2dError
+WindowCreationError = errors.WindowCreationError
+WindowClosedError = errors.WindowClosedError
+InvalidTransformError = errors.InvalidTransformError
+UnsupportedOpenGLVersion = errors.UnsupportedOpenGLVersion
@@ -0,0 +1,5 @@
+class Piston2dError(Exception): ...
+class WindowCreationError(Piston2dError): ...
+class WindowClosedError(Piston2dError): ...
+class InvalidTransformError(Piston2dError): ...
+class UnsupportedOpenGLVersion(Piston2dError): ...
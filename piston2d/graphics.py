@@ -3,9 +3,29 @@ from .piston2d import graphics
 __doc__ = graphics.__doc__
 
 Context = graphics.Context
+Matrix2d = graphics.Matrix2d
+DrawState = graphics.DrawState
 rectangle = graphics.rectangle
 circle_arc = graphics.circle_arc
+ellipse = graphics.ellipse
+line = graphics.line
+line_from_to = graphics.line_from_to
+polygon = graphics.polygon
+polyline = graphics.polyline
+image = graphics.image
+image_src = graphics.image_src
+text = graphics.text
+measure_text = graphics.measure_text
 
 # Due to limitations of pyo3, we must set modules manually
 rectangle.__module__ = "piston2d.graphics"
-circle_arc.__module__ = "piston2d.graphics"
\ No newline at end of file
+circle_arc.__module__ = "piston2d.graphics"
+ellipse.__module__ = "piston2d.graphics"
+line.__module__ = "piston2d.graphics"
+line_from_to.__module__ = "piston2d.graphics"
+polygon.__module__ = "piston2d.graphics"
+polyline.__module__ = "piston2d.graphics"
+image.__module__ = "piston2d.graphics"
+image_src.__module__ = "piston2d.graphics"
+text.__module__ = "piston2d.graphics"
+measure_text.__module__ = "piston2d.graphics"
\ No newline at end of file
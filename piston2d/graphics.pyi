@@ -1,22 +1,138 @@
-from typing import List, Optional, Tuple
+from typing import List, Optional, Tuple, Union
 
-from .piston2d.graphics import GlGraphics
+from .opengl import GlGraphics, GlyphCache, Texture
+from .software import SoftwareGlyphCache, SoftwareGraphics, SoftwareTexture
 from .piston2d.window.events import Viewport
 
+Backend = Union[GlGraphics, SoftwareGraphics]
+BackendTexture = Union[Texture, SoftwareTexture]
+BackendGlyphCache = Union[GlyphCache, SoftwareGlyphCache]
+
+
+class Matrix2d:
+    def __init__(self, rows: Optional[List[List[float]]] = None): ...
+    def to_list(self) -> List[List[float]]: ...
+    def trans(self, x: float, y: float) -> Matrix2d: ...
+    def rot_rad(self, angle: float) -> Matrix2d: ...
+    def rot_deg(self, angle: float) -> Matrix2d: ...
+    def orient(self, x: float, y: float) -> Matrix2d: ...
+    def scale(self, sx: float, sy: float) -> Matrix2d: ...
+    def zoom(self, s: float) -> Matrix2d: ...
+    def shear(self, x: float, y: float) -> Matrix2d: ...
+    def flip_h(self) -> Matrix2d: ...
+    def flip_v(self) -> Matrix2d: ...
+    def flip_hv(self) -> Matrix2d: ...
+    def append_transform(self, transform: Transform) -> Matrix2d: ...
+    def prepend_transform(self, transform: Transform) -> Matrix2d: ...
+    def invert(self) -> Matrix2d: ...
+    def transform_pos(self, x: float, y: float) -> Tuple[float, float]: ...
+    def __mul__(self, other: Matrix2d) -> Matrix2d: ...
+
+
+Transform = Union[Matrix2d, List[List[float]]]
+
+
+class DrawState:
+    def __init__(self): ...
+    @staticmethod
+    def new_alpha() -> DrawState: ...
+    @staticmethod
+    def new_clip() -> DrawState: ...
+    @staticmethod
+    def new_increment() -> DrawState: ...
+    @staticmethod
+    def new_inside() -> DrawState: ...
+    @staticmethod
+    def new_outside() -> DrawState: ...
+    def scissor(self, rect: Optional[Tuple[int, int, int, int]]) -> DrawState: ...
+    def blend(self, mode: Optional[str]) -> DrawState: ...
+    def stencil(self, mode: Optional[str], value: int = 255) -> DrawState: ...
+    @property
+    def scissor_rect(self) -> Optional[Tuple[int, int, int, int]]: ...
+    @property
+    def blend_mode(self) -> Optional[str]: ...
+    @property
+    def stencil_mode(self) -> Optional[str]: ...
+    @property
+    def stencil_value(self) -> Optional[int]: ...
+
 
 class Context:
     @property
     def viewport(self) -> Optional[Viewport]: ...
-    def reset(self): ...
-    def store_view(self): ...
+    @property
+    def view(self) -> List[List[float]]: ...
     def transform(self) -> List[List[float]]: ...
     @property
+    def draw_state(self) -> DrawState: ...
+    @property
+    def matrix(self) -> Matrix2d: ...
+    def reset(self) -> Context: ...
+    def store_view(self) -> Context: ...
+    def view_transform(self) -> Context: ...
+    def trans(self, x: float, y: float) -> Context: ...
+    def rot_rad(self, angle: float) -> Context: ...
+    def rot_deg(self, angle: float) -> Context: ...
+    def orient(self, x: float, y: float) -> Context: ...
+    def scale(self, sx: float, sy: float) -> Context: ...
+    def zoom(self, s: float) -> Context: ...
+    def shear(self, x: float, y: float) -> Context: ...
+    def flip_h(self) -> Context: ...
+    def flip_v(self) -> Context: ...
+    def flip_hv(self) -> Context: ...
+    def append_transform(self, transform: Transform) -> Context: ...
+    def prepend_transform(self, transform: Transform) -> Context: ...
+    @property
     def view_size(self) -> Tuple[float, float]: ...
 
 
 def rectangle(color: List[float], rect: List[float],
-              transform: List[List[float]], g: GlGraphics): ...
+              transform: Transform, g: Backend,
+              draw_state: Optional[DrawState] = None): ...
+
+
+def circle_arc(color: List[float], radius: float, start: float, end: float,
+               rect: List[float], transform: Transform, g: Backend,
+               draw_state: Optional[DrawState] = None): ...
+
+
+def ellipse(color: List[float], rect: List[float],
+            transform: Transform, g: Backend,
+            draw_state: Optional[DrawState] = None): ...
+
+
+def line(color: List[float], radius: float, line: List[float],
+         transform: Transform, g: Backend, shape: str = "square",
+         draw_state: Optional[DrawState] = None): ...
+
+
+def line_from_to(color: List[float], radius: float, start: Tuple[float, float],
+                 end: Tuple[float, float], transform: Transform, g: Backend,
+                 shape: str = "square", draw_state: Optional[DrawState] = None): ...
+
+
+def polygon(color: List[float], points: List[Tuple[float, float]],
+            transform: Transform, g: Backend,
+            draw_state: Optional[DrawState] = None): ...
+
+
+def polyline(color: List[float], radius: float, points: List[Tuple[float, float]],
+             transform: Transform, g: Backend, shape: str = "round",
+             draw_state: Optional[DrawState] = None): ...
+
+
+def image(texture: BackendTexture, transform: Transform, g: Backend,
+          draw_state: Optional[DrawState] = None): ...
+
+
+def image_src(texture: BackendTexture, src_rect: List[float], rect: List[float],
+              transform: Transform, g: Backend,
+              draw_state: Optional[DrawState] = None): ...
+
+
+def text(color: List[float], font_size: int, string: str, cache: BackendGlyphCache,
+         transform: Transform, g: Backend,
+         draw_state: Optional[DrawState] = None): ...
 
 
-def circle_arc(color: List[float], start: float, end: float,
-               rect: List[float], transform: List[List[float]], g: GlGraphics): ...
+def measure_text(font_size: int, string: str, cache: BackendGlyphCache) -> Tuple[float, float]: ...
@@ -1,4 +1,276 @@
-from typing import Optional
+from typing import ClassVar, Optional
+
+
+class Key:
+    Unknown: ClassVar[Key]
+    Backspace: ClassVar[Key]
+    Tab: ClassVar[Key]
+    Return: ClassVar[Key]
+    Escape: ClassVar[Key]
+    Space: ClassVar[Key]
+    Exclaim: ClassVar[Key]
+    Quotedbl: ClassVar[Key]
+    Hash: ClassVar[Key]
+    Dollar: ClassVar[Key]
+    Percent: ClassVar[Key]
+    Ampersand: ClassVar[Key]
+    Quote: ClassVar[Key]
+    LeftParen: ClassVar[Key]
+    RightParen: ClassVar[Key]
+    Asterisk: ClassVar[Key]
+    Plus: ClassVar[Key]
+    Comma: ClassVar[Key]
+    Minus: ClassVar[Key]
+    Period: ClassVar[Key]
+    Slash: ClassVar[Key]
+    D0: ClassVar[Key]
+    D1: ClassVar[Key]
+    D2: ClassVar[Key]
+    D3: ClassVar[Key]
+    D4: ClassVar[Key]
+    D5: ClassVar[Key]
+    D6: ClassVar[Key]
+    D7: ClassVar[Key]
+    D8: ClassVar[Key]
+    D9: ClassVar[Key]
+    Colon: ClassVar[Key]
+    Semicolon: ClassVar[Key]
+    Less: ClassVar[Key]
+    Equals: ClassVar[Key]
+    Greater: ClassVar[Key]
+    Question: ClassVar[Key]
+    At: ClassVar[Key]
+    LeftBracket: ClassVar[Key]
+    Backslash: ClassVar[Key]
+    RightBracket: ClassVar[Key]
+    Caret: ClassVar[Key]
+    Underscore: ClassVar[Key]
+    Backquote: ClassVar[Key]
+    A: ClassVar[Key]
+    B: ClassVar[Key]
+    C: ClassVar[Key]
+    D: ClassVar[Key]
+    E: ClassVar[Key]
+    F: ClassVar[Key]
+    G: ClassVar[Key]
+    H: ClassVar[Key]
+    I: ClassVar[Key]
+    J: ClassVar[Key]
+    K: ClassVar[Key]
+    L: ClassVar[Key]
+    M: ClassVar[Key]
+    N: ClassVar[Key]
+    O: ClassVar[Key]
+    P: ClassVar[Key]
+    Q: ClassVar[Key]
+    R: ClassVar[Key]
+    S: ClassVar[Key]
+    T: ClassVar[Key]
+    U: ClassVar[Key]
+    V: ClassVar[Key]
+    W: ClassVar[Key]
+    X: ClassVar[Key]
+    Y: ClassVar[Key]
+    Z: ClassVar[Key]
+    Delete: ClassVar[Key]
+    CapsLock: ClassVar[Key]
+    F1: ClassVar[Key]
+    F2: ClassVar[Key]
+    F3: ClassVar[Key]
+    F4: ClassVar[Key]
+    F5: ClassVar[Key]
+    F6: ClassVar[Key]
+    F7: ClassVar[Key]
+    F8: ClassVar[Key]
+    F9: ClassVar[Key]
+    F10: ClassVar[Key]
+    F11: ClassVar[Key]
+    F12: ClassVar[Key]
+    PrintScreen: ClassVar[Key]
+    ScrollLock: ClassVar[Key]
+    Pause: ClassVar[Key]
+    Insert: ClassVar[Key]
+    Home: ClassVar[Key]
+    PageUp: ClassVar[Key]
+    End: ClassVar[Key]
+    PageDown: ClassVar[Key]
+    Right: ClassVar[Key]
+    Left: ClassVar[Key]
+    Down: ClassVar[Key]
+    Up: ClassVar[Key]
+    NumLockClear: ClassVar[Key]
+    NumPadDivide: ClassVar[Key]
+    NumPadMultiply: ClassVar[Key]
+    NumPadMinus: ClassVar[Key]
+    NumPadPlus: ClassVar[Key]
+    NumPadEnter: ClassVar[Key]
+    NumPad1: ClassVar[Key]
+    NumPad2: ClassVar[Key]
+    NumPad3: ClassVar[Key]
+    NumPad4: ClassVar[Key]
+    NumPad5: ClassVar[Key]
+    NumPad6: ClassVar[Key]
+    NumPad7: ClassVar[Key]
+    NumPad8: ClassVar[Key]
+    NumPad9: ClassVar[Key]
+    NumPad0: ClassVar[Key]
+    NumPadPeriod: ClassVar[Key]
+    Application: ClassVar[Key]
+    Power: ClassVar[Key]
+    NumPadEquals: ClassVar[Key]
+    F13: ClassVar[Key]
+    F14: ClassVar[Key]
+    F15: ClassVar[Key]
+    F16: ClassVar[Key]
+    F17: ClassVar[Key]
+    F18: ClassVar[Key]
+    F19: ClassVar[Key]
+    F20: ClassVar[Key]
+    F21: ClassVar[Key]
+    F22: ClassVar[Key]
+    F23: ClassVar[Key]
+    F24: ClassVar[Key]
+    Execute: ClassVar[Key]
+    Help: ClassVar[Key]
+    Menu: ClassVar[Key]
+    Select: ClassVar[Key]
+    Stop: ClassVar[Key]
+    Again: ClassVar[Key]
+    Undo: ClassVar[Key]
+    Cut: ClassVar[Key]
+    Copy: ClassVar[Key]
+    Paste: ClassVar[Key]
+    Find: ClassVar[Key]
+    Mute: ClassVar[Key]
+    VolumeUp: ClassVar[Key]
+    VolumeDown: ClassVar[Key]
+    NumPadComma: ClassVar[Key]
+    NumPadEqualsAS400: ClassVar[Key]
+    AltErase: ClassVar[Key]
+    Sysreq: ClassVar[Key]
+    Cancel: ClassVar[Key]
+    Clear: ClassVar[Key]
+    Prior: ClassVar[Key]
+    Return2: ClassVar[Key]
+    Separator: ClassVar[Key]
+    Out: ClassVar[Key]
+    Oper: ClassVar[Key]
+    ClearAgain: ClassVar[Key]
+    CrSel: ClassVar[Key]
+    ExSel: ClassVar[Key]
+    NumPad00: ClassVar[Key]
+    NumPad000: ClassVar[Key]
+    ThousandsSeparator: ClassVar[Key]
+    DecimalSeparator: ClassVar[Key]
+    CurrencyUnit: ClassVar[Key]
+    CurrencySubUnit: ClassVar[Key]
+    NumPadLeftParen: ClassVar[Key]
+    NumPadRightParen: ClassVar[Key]
+    NumPadLeftBrace: ClassVar[Key]
+    NumPadRightBrace: ClassVar[Key]
+    NumPadTab: ClassVar[Key]
+    NumPadBackspace: ClassVar[Key]
+    NumPadA: ClassVar[Key]
+    NumPadB: ClassVar[Key]
+    NumPadC: ClassVar[Key]
+    NumPadD: ClassVar[Key]
+    NumPadE: ClassVar[Key]
+    NumPadF: ClassVar[Key]
+    NumPadXor: ClassVar[Key]
+    NumPadPower: ClassVar[Key]
+    NumPadPercent: ClassVar[Key]
+    NumPadLess: ClassVar[Key]
+    NumPadGreater: ClassVar[Key]
+    NumPadAmpersand: ClassVar[Key]
+    NumPadDblAmpersand: ClassVar[Key]
+    NumPadVerticalBar: ClassVar[Key]
+    NumPadDblVerticalBar: ClassVar[Key]
+    NumPadColon: ClassVar[Key]
+    NumPadHash: ClassVar[Key]
+    NumPadSpace: ClassVar[Key]
+    NumPadAt: ClassVar[Key]
+    NumPadExclam: ClassVar[Key]
+    NumPadMemStore: ClassVar[Key]
+    NumPadMemRecall: ClassVar[Key]
+    NumPadMemClear: ClassVar[Key]
+    NumPadMemAdd: ClassVar[Key]
+    NumPadMemSubtract: ClassVar[Key]
+    NumPadMemMultiply: ClassVar[Key]
+    NumPadMemDivide: ClassVar[Key]
+    NumPadPlusMinus: ClassVar[Key]
+    NumPadClear: ClassVar[Key]
+    NumPadClearEntry: ClassVar[Key]
+    NumPadBinary: ClassVar[Key]
+    NumPadOctal: ClassVar[Key]
+    NumPadDecimal: ClassVar[Key]
+    NumPadHexadecimal: ClassVar[Key]
+    LCtrl: ClassVar[Key]
+    LShift: ClassVar[Key]
+    LAlt: ClassVar[Key]
+    LGui: ClassVar[Key]
+    RCtrl: ClassVar[Key]
+    RShift: ClassVar[Key]
+    RAlt: ClassVar[Key]
+    RGui: ClassVar[Key]
+    Mode: ClassVar[Key]
+    AudioNext: ClassVar[Key]
+    AudioPrev: ClassVar[Key]
+    AudioStop: ClassVar[Key]
+    AudioPlay: ClassVar[Key]
+    AudioMute: ClassVar[Key]
+    MediaSelect: ClassVar[Key]
+    Www: ClassVar[Key]
+    Mail: ClassVar[Key]
+    Calculator: ClassVar[Key]
+    Computer: ClassVar[Key]
+    AcSearch: ClassVar[Key]
+    AcHome: ClassVar[Key]
+    AcBack: ClassVar[Key]
+    AcForward: ClassVar[Key]
+    AcStop: ClassVar[Key]
+    AcRefresh: ClassVar[Key]
+    AcBookmarks: ClassVar[Key]
+    BrightnessDown: ClassVar[Key]
+    BrightnessUp: ClassVar[Key]
+    DisplaySwitch: ClassVar[Key]
+    KbdIllumToggle: ClassVar[Key]
+    KbdIllumDown: ClassVar[Key]
+    KbdIllumUp: ClassVar[Key]
+    Eject: ClassVar[Key]
+    Sleep: ClassVar[Key]
+    def __init__(self, code: int) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def code(self) -> int: ...
+
+
+class MouseButton:
+    Unknown: ClassVar[MouseButton]
+    Left: ClassVar[MouseButton]
+    Right: ClassVar[MouseButton]
+    Middle: ClassVar[MouseButton]
+    X1: ClassVar[MouseButton]
+    X2: ClassVar[MouseButton]
+    Button6: ClassVar[MouseButton]
+    Button7: ClassVar[MouseButton]
+    Button8: ClassVar[MouseButton]
+    def __init__(self, code: int) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def code(self) -> int: ...
+
+
+Mouse = MouseButton
+
+
+class ControllerButton:
+    def __init__(self, id: int, button: int) -> None: ...
+    @property
+    def id(self) -> int: ...
+    @property
+    def button(self) -> int: ...
 
 
 class Button:
@@ -6,4 +278,10 @@ class Button:
     def is_controller(self) -> bool: ...
     def is_hat(self) -> bool: ...
     def is_mouse(self) -> bool: ...
+    @property
+    def key(self) -> Optional[Key]: ...
+    @property
+    def mouse(self) -> Optional[MouseButton]: ...
+    @property
+    def controller(self) -> Optional[ControllerButton]: ...
     def value(self) -> Optional[int]: ...
@@ -6,6 +6,10 @@ from typing import Any, Callable
 __doc__ = opengl.__doc__
 
 GlGraphics = opengl.GlGraphics
+RenderTarget = opengl.RenderTarget
+Texture = opengl.Texture
+TextureSettings = opengl.TextureSettings
+GlyphCache = opengl.GlyphCache
 
 def draw(gl: GlGraphics, viewport: Viewport, func: Callable[[Context, "GlGraphics"], None]) -> None:
     """
@@ -1,11 +1,80 @@
-from typing import Callable, List
+from typing import Callable, List, Optional, Tuple
+from .window import Window
 from .window.events import Viewport
 from .graphics import Context
 
+class RenderTarget:
+    def __init__(self, width: int, height: int) -> None: ...
+    @property
+    def size(self) -> Tuple[int, int]: ...
+    def viewport(self) -> Viewport: ...
+    def read_pixels(self) -> bytes: ...
+    def save_png(self, path: str): ...
+
+class TextureSettings:
+    def __init__(self) -> None: ...
+    @property
+    def convert_gamma(self) -> bool: ...
+    @convert_gamma.setter
+    def convert_gamma(self, value: bool) -> None: ...
+    @property
+    def compress(self) -> bool: ...
+    @compress.setter
+    def compress(self, value: bool) -> None: ...
+    @property
+    def generate_mipmap(self) -> bool: ...
+    @generate_mipmap.setter
+    def generate_mipmap(self, value: bool) -> None: ...
+    @property
+    def min(self) -> str: ...
+    @min.setter
+    def min(self, value: str) -> None: ...
+    @property
+    def mag(self) -> str: ...
+    @mag.setter
+    def mag(self, value: str) -> None: ...
+    @property
+    def mipmap(self) -> str: ...
+    @mipmap.setter
+    def mipmap(self, value: str) -> None: ...
+    @property
+    def filter(self): ...
+    @filter.setter
+    def filter(self, value: str) -> None: ...
+    @property
+    def wrap_u(self) -> str: ...
+    @wrap_u.setter
+    def wrap_u(self, value: str) -> None: ...
+    @property
+    def wrap_v(self) -> str: ...
+    @wrap_v.setter
+    def wrap_v(self, value: str) -> None: ...
+    @property
+    def border_color(self) -> Tuple[float, float, float, float]: ...
+    @border_color.setter
+    def border_color(self, value: Tuple[float, float, float, float]) -> None: ...
+
+class Texture:
+    @staticmethod
+    def from_path(path: str, settings: Optional[TextureSettings] = None) -> Texture: ...
+    @staticmethod
+    def from_bytes(data: bytes, width: int, height: int, settings: Optional[TextureSettings] = None) -> Texture: ...
+    @property
+    def size(self) -> Tuple[int, int]: ...
+
+class GlyphCache:
+    @staticmethod
+    def from_path(path: str, settings: Optional[TextureSettings] = None) -> GlyphCache: ...
+    @staticmethod
+    def from_bytes(data: bytes, settings: Optional[TextureSettings] = None) -> GlyphCache: ...
+
 class GlGraphics:
+    def __init__(self, opengl: str, window: Optional[Window] = None) -> None: ...
+    @property
+    def window(self) -> Optional[Window]: ...
     def clear_color(self, color: List[float]): ...
     def clear_stencil(self, value: int): ...
-    def draw_begin(self, viewport: Viewport) -> Context: ...
+    def draw_begin(self, viewport: Optional[Viewport] = None, target: Optional[RenderTarget] = None) -> Context: ...
     def draw_end(self): ...
 
 def draw(gl: GlGraphics, viewport: Viewport, func: Callable[[Context, "GlGraphics"], None]): ...
\ No newline at end of file
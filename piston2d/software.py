@@ -0,0 +1,28 @@
+from .piston2d import software
+from .graphics import Context
+from .window.events import Viewport
+from typing import Callable, Optional
+
+__doc__ = software.__doc__
+
+SoftwareGraphics = software.SoftwareGraphics
+SoftwareTexture = software.SoftwareTexture
+SoftwareGlyphCache = software.SoftwareGlyphCache
+
+def draw(g: SoftwareGraphics, func: Callable[[Context, "SoftwareGraphics"], None], viewport: Optional[Viewport] = None) -> None:
+    """
+    Wrapper around the :func:`SoftwareGraphics.draw_begin` and
+    :func:`SoftwareGraphics.draw_end` pair, mirroring :func:`opengl.draw`.
+
+    :param g: The graphics instance to use
+    :type g: SoftwareGraphics
+    :param func: The function to call with the context (:class:`Context`)
+    :type func: (p1: :class:`Context`, p2: :class:`SoftwareGraphics`) -> ``None``
+    :param viewport: The viewport, defaulting to the whole buffer
+    :type viewport: Optional[Viewport]
+    """
+    ctx = g.draw_begin(viewport)
+
+    func(ctx, g)
+
+    g.draw_end()
\ No newline at end of file
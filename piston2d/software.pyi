@@ -0,0 +1,32 @@
+from typing import Callable, List, Optional, Tuple
+from .window.events import Viewport
+from .graphics import Context
+
+class SoftwareGraphics:
+    def __init__(self, width: int, height: int) -> None: ...
+    @property
+    def size(self) -> Tuple[int, int]: ...
+    def viewport(self) -> Viewport: ...
+    def clear_color(self, color: List[float]): ...
+    def clear_stencil(self, value: int): ...
+    def draw_begin(self, viewport: Optional[Viewport] = None) -> Context: ...
+    def draw_end(self): ...
+    def get_pixel(self, x: int, y: int) -> Tuple[int, int, int, int]: ...
+    def read_pixels(self) -> bytes: ...
+    def save_png(self, path: str): ...
+
+class SoftwareTexture:
+    @staticmethod
+    def from_path(path: str) -> SoftwareTexture: ...
+    @staticmethod
+    def from_bytes(data: bytes, width: int, height: int) -> SoftwareTexture: ...
+    @property
+    def size(self) -> Tuple[int, int]: ...
+
+class SoftwareGlyphCache:
+    @staticmethod
+    def from_path(path: str) -> SoftwareGlyphCache: ...
+    @staticmethod
+    def from_bytes(data: bytes) -> SoftwareGlyphCache: ...
+
+def draw(g: SoftwareGraphics, func: Callable[[Context, "SoftwareGraphics"], None], viewport: Optional[Viewport] = None): ...
\ No newline at end of file
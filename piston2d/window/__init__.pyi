@@ -1,9 +1,17 @@
-from typing import Any, Dict, Optional, Tuple
+from typing import Optional, Tuple
 from . import events
 
 class Window:
     def __init__(self, settings: WindowSettings) -> None: ...
     @property
+    def id(self) -> int: ...
+    @property
+    def settings(self) -> WindowSettings: ...
+    def duplicate(self) -> Window: ...
+    def __copy__(self) -> Window: ...
+    @property
+    def headless(self) -> bool: ...
+    @property
     def title(self) -> str: ...
     @title.setter
     def title(self, value: str) -> None: ...
@@ -42,9 +50,15 @@ class Window:
     def swap_buffers(self): ...
     def wait_event(self) -> events.Event: ...
     def wait_event_timeout(self, seconds: float) -> Optional[events.Event]: ...
+    def push_event(self, event: events.Event) -> None: ...
 
 class WindowSettings:
-    def __init__(self, title: str, size: Tuple[int, int], **kwargs: Dict[str, Any]) -> None: ...
+    def __init__(self, title: str, size: Tuple[int, int], headless: bool = False) -> None: ...
+
+    @property
+    def headless(self) -> bool: ...
+    @headless.setter
+    def headless(self, value: bool) -> None: ...
 
     @property
     def title(self) -> str: ...
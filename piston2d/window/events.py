@@ -4,7 +4,47 @@ __doc__ = window.events.__doc__
 
 Event = window.events.Event
 Events = window.events.Events
+MultiEvents = window.events.MultiEvents
 EventSettings = window.events.EventSettings
 UpdateArgs = window.events.UpdateArgs
 RenderArgs = window.events.RenderArgs
 Viewport = window.events.Viewport
+MouseCursorArgs = window.events.MouseCursorArgs
+MouseRelativeArgs = window.events.MouseRelativeArgs
+MouseScrollArgs = window.events.MouseScrollArgs
+TextArgs = window.events.TextArgs
+ResizeArgs = window.events.ResizeArgs
+FocusArgs = window.events.FocusArgs
+CursorArgs = window.events.CursorArgs
+CloseArgs = window.events.CloseArgs
+IdleArgs = window.events.IdleArgs
+AfterRenderArgs = window.events.AfterRenderArgs
+ControllerAxisArgs = window.events.ControllerAxisArgs
+TouchArgs = window.events.TouchArgs
+FileDragArgs = window.events.FileDragArgs
+EventKind = window.events.EventKind
+Render = window.events.Render
+AfterRender = window.events.AfterRender
+Update = window.events.Update
+Idle = window.events.Idle
+KeyPress = window.events.KeyPress
+KeyRelease = window.events.KeyRelease
+MousePress = window.events.MousePress
+MouseRelease = window.events.MouseRelease
+ButtonPress = window.events.ButtonPress
+ButtonRelease = window.events.ButtonRelease
+MouseMove = window.events.MouseMove
+MouseRelative = window.events.MouseRelative
+MouseScroll = window.events.MouseScroll
+ControllerAxis = window.events.ControllerAxis
+Touch = window.events.Touch
+Text = window.events.Text
+Resize = window.events.Resize
+Focus = window.events.Focus
+Cursor = window.events.Cursor
+FileHover = window.events.FileHover
+FileDrop = window.events.FileDrop
+FileCancel = window.events.FileCancel
+Close = window.events.Close
+EventRecorder = window.events.EventRecorder
+EventPlayer = window.events.EventPlayer
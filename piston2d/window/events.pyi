@@ -1,9 +1,42 @@
-from piston2d.piston2d.input import Button
+from piston2d.piston2d.input import Button, ControllerButton, Key, MouseButton
 from piston2d.piston2d.window import Window
-from typing import List, Optional, Tuple
+from typing import ClassVar, List, Literal, Optional, Tuple, Union
+
+
+class EventKind:
+    RENDER: ClassVar[EventKind]
+    AFTER_RENDER: ClassVar[EventKind]
+    UPDATE: ClassVar[EventKind]
+    IDLE: ClassVar[EventKind]
+    KEY_PRESS: ClassVar[EventKind]
+    KEY_RELEASE: ClassVar[EventKind]
+    MOUSE_PRESS: ClassVar[EventKind]
+    MOUSE_RELEASE: ClassVar[EventKind]
+    BUTTON_PRESS: ClassVar[EventKind]
+    BUTTON_RELEASE: ClassVar[EventKind]
+    MOUSE_MOVE: ClassVar[EventKind]
+    MOUSE_RELATIVE: ClassVar[EventKind]
+    MOUSE_SCROLL: ClassVar[EventKind]
+    CONTROLLER_AXIS: ClassVar[EventKind]
+    TOUCH: ClassVar[EventKind]
+    TEXT: ClassVar[EventKind]
+    RESIZE: ClassVar[EventKind]
+    FOCUS: ClassVar[EventKind]
+    CURSOR: ClassVar[EventKind]
+    FILE_HOVER: ClassVar[EventKind]
+    FILE_DROP: ClassVar[EventKind]
+    FILE_CANCEL: ClassVar[EventKind]
+    CLOSE: ClassVar[EventKind]
+    CUSTOM: ClassVar[EventKind]
+    @property
+    def name(self) -> str: ...
 
 
 class Event:
+    @property
+    def kind(self) -> EventKind: ...
+    @property
+    def window_id(self) -> Optional[int]: ...
     def is_input(self) -> bool: ...
     def is_loop(self) -> bool: ...
 
@@ -15,6 +48,199 @@ class Event:
     def release_args(self) -> Optional[Button]: ...
     def keyrelease_args(self) -> Optional[Button]: ...
     def mouserelease_args(self) -> Optional[Button]: ...
+    def mouse_cursor_args(self) -> Optional[MouseCursorArgs]: ...
+    def mouse_relative_args(self) -> Optional[MouseRelativeArgs]: ...
+    def mouse_scroll_args(self) -> Optional[MouseScrollArgs]: ...
+    def text_args(self) -> Optional[TextArgs]: ...
+    def resize_args(self) -> Optional[ResizeArgs]: ...
+    def focus_args(self) -> Optional[FocusArgs]: ...
+    def cursor_args(self) -> Optional[CursorArgs]: ...
+    def close_args(self) -> Optional[CloseArgs]: ...
+    def idle_args(self) -> Optional[IdleArgs]: ...
+    def after_render_args(self) -> Optional[AfterRenderArgs]: ...
+    def controller_axis_args(self) -> Optional[ControllerAxisArgs]: ...
+    def touch_args(self) -> Optional[TouchArgs]: ...
+    def file_drag_args(self) -> Optional[FileDragArgs]: ...
+
+    @staticmethod
+    def key_press(key: Union[Key, Button, int], scancode: Optional[int] = None) -> KeyPress: ...
+    @staticmethod
+    def key_release(key: Union[Key, Button, int], scancode: Optional[int] = None) -> KeyRelease: ...
+    @staticmethod
+    def mouse_press(button: Union[MouseButton, Button, int]) -> MousePress: ...
+    @staticmethod
+    def mouse_release(button: Union[MouseButton, Button, int]) -> MouseRelease: ...
+    @staticmethod
+    def button_press(button: Union[Button, Key, MouseButton, ControllerButton, int]) -> Event: ...
+    @staticmethod
+    def button_release(button: Union[Button, Key, MouseButton, ControllerButton, int]) -> Event: ...
+    @staticmethod
+    def mouse_move(x: float, y: float) -> MouseMove: ...
+    @staticmethod
+    def mouse_relative(dx: float, dy: float) -> MouseRelative: ...
+    @staticmethod
+    def mouse_scroll(dx: float, dy: float) -> MouseScroll: ...
+    @staticmethod
+    def controller_axis(id: int, axis: int, position: float) -> ControllerAxis: ...
+    @staticmethod
+    def text(text: str) -> Text: ...
+    @staticmethod
+    def resize(width: float, height: float) -> Resize: ...
+    @staticmethod
+    def focus(focused: bool) -> Focus: ...
+    @staticmethod
+    def cursor(inside: bool) -> Cursor: ...
+    @staticmethod
+    def file_drop(path: str) -> FileDrop: ...
+    @staticmethod
+    def close() -> Close: ...
+    @staticmethod
+    def render(ext_dt: float, width: float, height: float) -> Render: ...
+    @staticmethod
+    def after_render() -> AfterRender: ...
+    @staticmethod
+    def update(dt: float) -> Update: ...
+    @staticmethod
+    def idle(dt: float) -> Idle: ...
+
+
+class Render(Event):
+    __match_args__ = ("ext_dt", "window_size", "draw_size")
+    @property
+    def ext_dt(self) -> float: ...
+    @property
+    def window_size(self) -> Tuple[float, float]: ...
+    @property
+    def draw_size(self) -> Tuple[int, int]: ...
+    @property
+    def viewport(self) -> Viewport: ...
+
+class AfterRender(Event): ...
+
+class Update(Event):
+    __match_args__ = ("dt",)
+    @property
+    def dt(self) -> float: ...
+
+class Idle(Event):
+    __match_args__ = ("dt",)
+    @property
+    def dt(self) -> float: ...
+
+class KeyPress(Event):
+    __match_args__ = ("key", "scancode")
+    @property
+    def key(self) -> Key: ...
+    @property
+    def scancode(self) -> Optional[int]: ...
+
+class KeyRelease(Event):
+    __match_args__ = ("key", "scancode")
+    @property
+    def key(self) -> Key: ...
+    @property
+    def scancode(self) -> Optional[int]: ...
+
+class MousePress(Event):
+    __match_args__ = ("button",)
+    @property
+    def button(self) -> MouseButton: ...
+
+class MouseRelease(Event):
+    __match_args__ = ("button",)
+    @property
+    def button(self) -> MouseButton: ...
+
+class ButtonPress(Event):
+    __match_args__ = ("button",)
+    @property
+    def button(self) -> Button: ...
+
+class ButtonRelease(Event):
+    __match_args__ = ("button",)
+    @property
+    def button(self) -> Button: ...
+
+class MouseMove(Event):
+    __match_args__ = ("x", "y")
+    @property
+    def x(self) -> float: ...
+    @property
+    def y(self) -> float: ...
+    @property
+    def position(self) -> Tuple[float, float]: ...
+
+class MouseRelative(Event):
+    __match_args__ = ("dx", "dy")
+    @property
+    def dx(self) -> float: ...
+    @property
+    def dy(self) -> float: ...
+
+class MouseScroll(Event):
+    __match_args__ = ("dx", "dy")
+    @property
+    def dx(self) -> float: ...
+    @property
+    def dy(self) -> float: ...
+
+class ControllerAxis(Event):
+    __match_args__ = ("id", "axis", "position")
+    @property
+    def id(self) -> int: ...
+    @property
+    def axis(self) -> int: ...
+    @property
+    def position(self) -> float: ...
+
+class Touch(Event):
+    __match_args__ = ("touch", "position", "pressure")
+    @property
+    def touch(self) -> Literal["start", "move", "end", "cancel"]: ...
+    @property
+    def position(self) -> Tuple[float, float]: ...
+    @property
+    def pressure(self) -> float: ...
+    @property
+    def id(self) -> int: ...
+    @property
+    def device(self) -> int: ...
+
+class Text(Event):
+    __match_args__ = ("text",)
+    @property
+    def text(self) -> str: ...
+
+class Resize(Event):
+    __match_args__ = ("window_size", "draw_size")
+    @property
+    def window_size(self) -> Tuple[float, float]: ...
+    @property
+    def draw_size(self) -> Tuple[int, int]: ...
+
+class Focus(Event):
+    __match_args__ = ("focused",)
+    @property
+    def focused(self) -> bool: ...
+
+class Cursor(Event):
+    __match_args__ = ("inside",)
+    @property
+    def inside(self) -> bool: ...
+
+class FileHover(Event):
+    __match_args__ = ("path",)
+    @property
+    def path(self) -> str: ...
+
+class FileDrop(Event):
+    __match_args__ = ("path",)
+    @property
+    def path(self) -> str: ...
+
+class FileCancel(Event): ...
+
+class Close(Event): ...
 
 
 class RenderArgs:
@@ -31,6 +257,88 @@ class UpdateArgs:
     @property
     def dt(self) -> float: ...
 
+class MouseCursorArgs:
+    @property
+    def x(self) -> float: ...
+    @property
+    def y(self) -> float: ...
+    @property
+    def position(self) -> Tuple[float, float]: ...
+
+class MouseRelativeArgs:
+    @property
+    def dx(self) -> float: ...
+    @property
+    def dy(self) -> float: ...
+    @property
+    def delta(self) -> Tuple[float, float]: ...
+
+class MouseScrollArgs:
+    @property
+    def dx(self) -> float: ...
+    @property
+    def dy(self) -> float: ...
+    @property
+    def delta(self) -> Tuple[float, float]: ...
+
+class TextArgs:
+    @property
+    def text(self) -> str: ...
+
+class ResizeArgs:
+    @property
+    def window_size(self) -> Tuple[float, float]: ...
+    @property
+    def draw_size(self) -> Tuple[int, int]: ...
+
+class FocusArgs:
+    @property
+    def focused(self) -> bool: ...
+
+class CursorArgs:
+    @property
+    def inside(self) -> bool: ...
+
+class CloseArgs: ...
+
+class IdleArgs:
+    @property
+    def dt(self) -> float: ...
+
+class AfterRenderArgs: ...
+
+class ControllerAxisArgs:
+    @property
+    def id(self) -> int: ...
+    @property
+    def axis(self) -> int: ...
+    @property
+    def position(self) -> float: ...
+
+class TouchArgs:
+    @property
+    def device(self) -> int: ...
+    @property
+    def id(self) -> int: ...
+    @property
+    def position(self) -> Tuple[float, float]: ...
+    @property
+    def position_3d(self) -> Tuple[float, float, float]: ...
+    @property
+    def pressure(self) -> float: ...
+    @property
+    def pressure_3d(self) -> Tuple[float, float, float]: ...
+    @property
+    def is_3d(self) -> bool: ...
+    @property
+    def touch(self) -> Literal["start", "move", "end", "cancel"]: ...
+
+class FileDragArgs:
+    @property
+    def kind(self) -> Literal["hover", "drop", "cancel"]: ...
+    @property
+    def path(self) -> Optional[str]: ...
+
 class Viewport:
     @property
     def rect(self) -> List[float]: ...
@@ -40,21 +348,66 @@ class Viewport:
     def window_size(self) -> Tuple[float, float]: ...
 
 class EventSettings:
-    def __init__(self) -> None: ...
+    def __init__(self, max_fps: Optional[int] = None, ups: Optional[int] = None,
+                 ups_reset: Optional[int] = None, swap_buffers: Optional[bool] = None,
+                 bench_mode: Optional[bool] = None, lazy: Optional[bool] = None) -> None: ...
+    max_fps: int
+    ups: int
+    ups_reset: int
+    swap_buffers: bool
+    bench_mode: bool
+    lazy: bool
+
+class Events:
+    settings: EventSettings
+    def __init__(self, settings: EventSettings, window: Optional[Window] = None) -> None: ...
+    def next(self, window: Window) -> Optional[Event]: ...
+    def iter(self, window: Window) -> Events: ...
     @property
-    def max_fps(self) -> int: ...
+    def window(self) -> Optional[Window]: ...
+    def __iter__(self) -> Events: ...
+    def __next__(self) -> Event: ...
+    def set_max_fps(self, frames: int) -> None: ...
+    def set_ups(self, frames: int) -> None: ...
+    def set_ups_reset(self, frames: int) -> None: ...
+    def set_swap_buffers(self, enable: bool) -> None: ...
+    def set_bench_mode(self, enable: bool) -> None: ...
+    def set_lazy(self, enable: bool) -> None: ...
+
+
+class EventRecorder:
+    def __init__(self, events: Events, path: str) -> None: ...
     @property
-    def ups(self) -> int: ...
+    def events(self) -> Events: ...
     @property
-    def ups_reset(self) -> int: ...
+    def path(self) -> str: ...
     @property
-    def swap_buffers(self) -> bool: ...
+    def closed(self) -> bool: ...
+    def next(self, window: Window) -> Optional[Event]: ...
+    def close(self) -> None: ...
+    def __iter__(self) -> EventRecorder: ...
+    def __next__(self) -> Event: ...
+
+
+class EventPlayer:
+    def __init__(self, path: str) -> None: ...
     @property
-    def bench_mode(self) -> bool: ...
+    def time(self) -> float: ...
     @property
-    def lazy(self) -> bool: ...
+    def remaining(self) -> int: ...
+    def next(self, window: Optional[Window] = None) -> Optional[Event]: ...
+    def __iter__(self) -> EventPlayer: ...
+    def __next__(self) -> Event: ...
 
-class Events:
-    def __init__(self, settings: EventSettings) -> None: ...
-    def next(self, window: Window) -> Optional[Event]: ...
 
+class MultiEvents:
+    def __init__(self, settings: EventSettings, windows: List[Window] = []) -> None: ...
+    @property
+    def settings(self) -> EventSettings: ...
+    @property
+    def windows(self) -> List[Window]: ...
+    def add(self, window: Window) -> None: ...
+    def remove(self, window: Window) -> bool: ...
+    def next(self) -> Optional[Event]: ...
+    def __iter__(self) -> MultiEvents: ...
+    def __next__(self) -> Event: ...
@@ -0,0 +1,35 @@
+use pyo3::{create_exception, exceptions::PyException, prelude::*};
+
+create_exception!(errors, Piston2dError, PyException);
+create_exception!(errors, WindowCreationError, Piston2dError);
+create_exception!(errors, WindowClosedError, Piston2dError);
+create_exception!(errors, InvalidTransformError, Piston2dError);
+create_exception!(errors, UnsupportedOpenGLVersion, Piston2dError);
+
+pub fn init_submodule(py: Python, m: &PyModule) -> PyResult<()> {
+    m.add("Piston2dError", py.get_type::<Piston2dError>())?;
+    m.add("WindowCreationError", py.get_type::<WindowCreationError>())?;
+    m.add("WindowClosedError", py.get_type::<WindowClosedError>())?;
+    m.add(
+        "InvalidTransformError",
+        py.get_type::<InvalidTransformError>(),
+    )?;
+    m.add(
+        "UnsupportedOpenGLVersion",
+        py.get_type::<UnsupportedOpenGLVersion>(),
+    )?;
+
+    // create_exception! takes the module as an identifier, so cannot name it
+    // piston2d.errors
+    for name in &[
+        "Piston2dError",
+        "WindowCreationError",
+        "WindowClosedError",
+        "InvalidTransformError",
+        "UnsupportedOpenGLVersion",
+    ] {
+        m.getattr(name)?.setattr("__module__", "piston2d.errors")?;
+    }
+
+    Ok(())
+}
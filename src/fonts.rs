@@ -0,0 +1,44 @@
+use graphics::{character::CharacterCache, glyph_cache::rusttype::GlyphCache, ImageSize};
+use opengl_graphics::{CreateTexture, UpdateTexture};
+use pyo3::{
+    exceptions::{PyIOError, PyRuntimeError, PyValueError},
+    prelude::*,
+};
+use rusttype::{Font, Scale};
+
+/// Parse a TrueType font held in memory
+pub fn font_from_bytes(data: &[u8]) -> PyResult<Font<'static>> {
+    Font::try_from_vec(data.to_vec()).ok_or_else(|| PyValueError::new_err("invalid font data"))
+}
+
+/// Load a TrueType font file
+pub fn load_font(path: &str) -> PyResult<Font<'static>> {
+    let data = std::fs::read(path)
+        .map_err(|e| PyIOError::new_err(format!("could not load '{}': {}", path, e)))?;
+
+    Font::try_from_vec(data)
+        .ok_or_else(|| PyValueError::new_err(format!("'{}' is not a valid font", path)))
+}
+
+/// The width and height of a single line of text, in pixels.
+///
+/// The height spans the font's ascent and descent, so it is the same for any
+/// string at a given size.
+pub fn measure<F, T>(
+    cache: &mut GlyphCache<'static, F, T>,
+    font_size: u32,
+    text: &str,
+) -> PyResult<[f64; 2]>
+where
+    T: CreateTexture<F, Error = String> + UpdateTexture<F> + ImageSize,
+{
+    let width = cache
+        .width(font_size, text)
+        .map_err(PyRuntimeError::new_err)?;
+
+    // Same points to pixels conversion the glyph cache uses
+    let scale = Scale::uniform((font_size as f32 * 1.333).round());
+    let metrics = cache.font.v_metrics(scale);
+
+    Ok([width, (metrics.ascent - metrics.descent) as f64])
+}
@@ -1,13 +1,164 @@
 use core::f64;
 use graphics::Context as PistonContext;
-use graphics::{circle_arc as piston_circle_arc, math::Matrix2d, rectangle as piston_rectangle};
-use pyo3::{prelude::*, types::PyList, wrap_pyfunction};
-use std::{convert::TryInto, fmt::Debug};
+use graphics::{
+    draw_state::{Blend, Stencil},
+    line::Shape,
+    math::{self, Matrix2d as PistonMatrix2d},
+    CircleArc, DrawState as PistonDrawState, Ellipse, Image, Line, Polygon, Rectangle, Text,
+    Transformed,
+};
+use pyo3::{
+    basic::CompareOp,
+    exceptions::{PyRuntimeError, PyTypeError, PyValueError},
+    prelude::*,
+    types::PyList,
+    wrap_pyfunction, PyNativeType, PyNumberProtocol, PyObjectProtocol,
+};
+use std::convert::TryInto;
 
-use crate::{opengl::GlGraphics, window::events::Viewport};
+use crate::{
+    errors::InvalidTransformError,
+    fonts,
+    opengl::{self, GlGraphics, GlyphCache, Texture},
+    software::{SoftwareGlyphCache, SoftwareGraphics, SoftwareTexture},
+    window::events::Viewport,
+};
 
 type PyMatrix = PyList;
 
+/// Any of the graphics backends the drawing functions accept
+#[derive(FromPyObject)]
+pub enum Backend<'p> {
+    #[pyo3(annotation = "GlGraphics")]
+    Gl(PyRefMut<'p, GlGraphics>),
+    #[pyo3(annotation = "SoftwareGraphics")]
+    Software(PyRefMut<'p, SoftwareGraphics>),
+}
+
+/// Run `$body` with `$g` bound to the piston graphics of whichever backend
+/// `$backend` holds
+macro_rules! with_backend {
+    ($backend:expr, |$g:ident| $body:expr) => {
+        match $backend {
+            Backend::Gl(mut backend) => {
+                let $g = &mut backend._piston;
+                $body
+            }
+            Backend::Software(mut backend) => {
+                let $g = &mut backend._piston;
+                $body
+            }
+        }
+    };
+}
+
+/// Any of the texture types, each belonging to one backend
+#[derive(FromPyObject)]
+pub enum BackendTexture<'p> {
+    #[pyo3(annotation = "Texture")]
+    Gl(PyRefMut<'p, Texture>),
+    #[pyo3(annotation = "SoftwareTexture")]
+    Software(PyRefMut<'p, SoftwareTexture>),
+}
+
+/// Any of the glyph cache types, each belonging to one backend
+#[derive(FromPyObject)]
+pub enum BackendGlyphCache<'p> {
+    #[pyo3(annotation = "GlyphCache")]
+    Gl(PyRefMut<'p, GlyphCache>),
+    #[pyo3(annotation = "SoftwareGlyphCache")]
+    Software(PyRefMut<'p, SoftwareGlyphCache>),
+}
+
+/// Like `with_backend!`, also binding `$r` to the piston half of a resource
+/// (`$kind` being `BackendTexture` or `BackendGlyphCache`), which must belong
+/// to the same backend as the graphics
+macro_rules! with_backend_resource {
+    ($backend:expr, $resource:expr, $kind:ident, $name:literal, |$g:ident, $r:ident| $body:expr) => {
+        match ($backend, $resource) {
+            (Backend::Gl(mut backend), $kind::Gl(mut resource)) => {
+                let ($g, $r) = (&mut backend._piston, &mut resource._piston);
+                Ok($body)
+            }
+            (Backend::Software(mut backend), $kind::Software(mut resource)) => {
+                let ($g, $r) = (&mut backend._piston, &mut resource._piston);
+                Ok($body)
+            }
+            _ => Err(PyTypeError::new_err(concat!(
+                "the ",
+                $name,
+                " does not belong to the same backend as the graphics"
+            ))),
+        }
+    };
+}
+
+/// Parse the name of a line cap shape
+fn line_shape(name: &str) -> PyResult<Shape> {
+    match name {
+        "square" => Ok(Shape::Square),
+        "round" => Ok(Shape::Round),
+        "bevel" => Ok(Shape::Bevel),
+        _ => Err(PyValueError::new_err(format!(
+            "unknown line shape '{}', expected 'square', 'round' or 'bevel'",
+            name
+        ))),
+    }
+}
+
+/// Parse the name of a blend mode
+fn parse_blend(name: &str) -> PyResult<Blend> {
+    match name {
+        "alpha" => Ok(Blend::Alpha),
+        "add" => Ok(Blend::Add),
+        "lighter" => Ok(Blend::Lighter),
+        "multiply" => Ok(Blend::Multiply),
+        "invert" => Ok(Blend::Invert),
+        _ => Err(PyValueError::new_err(format!(
+            "unknown blend mode '{}', expected 'alpha', 'add', 'lighter', 'multiply' or 'invert'",
+            name
+        ))),
+    }
+}
+
+fn blend_name(blend: Blend) -> &'static str {
+    match blend {
+        Blend::Alpha => "alpha",
+        Blend::Add => "add",
+        Blend::Lighter => "lighter",
+        Blend::Multiply => "multiply",
+        Blend::Invert => "invert",
+    }
+}
+
+/// Parse the name of a stencil mode, using `value` as its stencil value
+fn parse_stencil(name: &str, value: u8) -> PyResult<Stencil> {
+    match name {
+        "clip" => Ok(Stencil::Clip(value)),
+        "inside" => Ok(Stencil::Inside(value)),
+        "outside" => Ok(Stencil::Outside(value)),
+        "increment" => Ok(Stencil::Increment),
+        _ => Err(PyValueError::new_err(format!(
+            "unknown stencil mode '{}', expected 'clip', 'inside', 'outside' or 'increment'",
+            name
+        ))),
+    }
+}
+
+fn stencil_name(stencil: Stencil) -> &'static str {
+    match stencil {
+        Stencil::Clip(_) => "clip",
+        Stencil::Inside(_) => "inside",
+        Stencil::Outside(_) => "outside",
+        Stencil::Increment => "increment",
+    }
+}
+
+/// The piston draw state to draw with, defaulting to alpha blending
+fn piston_draw_state(draw_state: Option<PyRef<DrawState>>) -> PistonDrawState {
+    draw_state.map_or_else(PistonDrawState::default, |d| d._piston)
+}
+
 /// Expand a PyList into a Vec<T>
 fn expand<'source, T: Clone + FromPyObject<'source>>(row: &'source PyMatrix) -> PyResult<Vec<T>>
 where
@@ -22,28 +173,56 @@ where
     Ok(res)
 }
 
+/// Expand one row of a PyList matrix into a native rust [T; 3]
+fn expand_row<'source, T>(matrix: &'source PyMatrix, index: isize) -> PyResult<[T; 3]>
+where
+    T: Clone + FromPyObject<'source> + 'source,
+{
+    let invalid = |reason: &str| {
+        InvalidTransformError::new_err(format!("row {} of the transform {}", index, reason))
+    };
+
+    let row = matrix
+        .get_item(index)
+        .cast_as::<PyList>()
+        .map_err(|_| invalid("is not a list"))?;
+    let row = expand::<T>(row).map_err(|_| invalid("must only contain numbers"))?;
+    let len = row.len();
+
+    row.try_into()
+        .map_err(|_| invalid(&format!("has {} values, expected 3", len)))
+}
+
 /// Expand a PyList into a native rust [[T; 3]; 2]
-fn expand2x3<'source, T: Clone + Debug + FromPyObject<'source>>(
-    matrix: &'source PyMatrix,
-) -> PyResult<[[T; 3]; 2]>
+fn expand2x3<'source, T>(matrix: &'source PyMatrix) -> PyResult<[[T; 3]; 2]>
 where
-    T: 'source,
+    T: Clone + FromPyObject<'source> + 'source,
 {
-    Ok([
-        expand::<T>(matrix.get_item(0).cast_as::<PyList>()?)?
-            .try_into()
-            .unwrap(),
-        expand::<T>(matrix.get_item(1).cast_as::<PyList>()?)?
-            .try_into()
-            .unwrap(),
-    ])
-}
-
-/// Create a Matrix2d from a PyList using expand2x3
-fn matrix2x3(transform: Option<&PyList>) -> PyResult<Matrix2d> {
+    if matrix.len() != 2 {
+        return Err(InvalidTransformError::new_err(format!(
+            "the transform has {} rows, expected 2",
+            matrix.len()
+        )));
+    }
+
+    Ok([expand_row(matrix, 0)?, expand_row(matrix, 1)?])
+}
+
+/// A transform given either as a :class:`Matrix2d` or in the nested list form
+#[derive(FromPyObject)]
+pub enum Transform<'p> {
+    #[pyo3(annotation = "Matrix2d")]
+    Matrix(PyRef<'p, Matrix2d>),
+    #[pyo3(annotation = "List[List[float]]")]
+    List(&'p PyList),
+}
+
+/// Create a Matrix2d from a transform, expanding the list form using expand2x3
+fn matrix2x3(transform: Option<Transform>) -> PyResult<PistonMatrix2d> {
     Ok(match transform {
-        Some(t) => Matrix2d::from(expand2x3(t)?),
-        None => Matrix2d::default(),
+        Some(Transform::Matrix(m)) => m._piston,
+        Some(Transform::List(t)) => PistonMatrix2d::from(expand2x3(t)?),
+        None => PistonMatrix2d::default(),
     })
 }
 
@@ -61,6 +240,434 @@ fn create_matrix2x3_pylist<T: ToPyObject + Clone>(transform: [[T; 3]; 2]) -> PyR
     Ok(list.to_object(py))
 }
 
+/// A 2x3 row-major transformation matrix.
+///
+/// Every operation returns a new matrix, leaving this one unchanged. Matrices
+/// compose with ``*``, where ``a * b`` is the same as
+/// ``a.append_transform(b)``.
+#[pyclass(module = "piston2d.graphics")]
+#[derive(Clone)]
+pub struct Matrix2d {
+    pub _piston: PistonMatrix2d,
+}
+
+impl From<PistonMatrix2d> for Matrix2d {
+    fn from(matrix: PistonMatrix2d) -> Self {
+        Matrix2d { _piston: matrix }
+    }
+}
+
+#[pymethods]
+impl Matrix2d {
+    /// Matrix2d(rows: Optional[List[List[float]]] = None) -> Matrix2d
+    ///
+    /// Create a matrix from nested lists (such as :func:`Context.transform`),
+    /// or the identity matrix
+    #[new]
+    #[args(rows = "None")]
+    fn new(rows: Option<&PyList>) -> PyResult<Self> {
+        Ok(match rows {
+            Some(rows) => Matrix2d::from(expand2x3(rows)?),
+            None => Matrix2d::from(math::identity()),
+        })
+    }
+
+    /// to_list() -> List[List[float]]
+    ///
+    /// The matrix in the nested list form
+    ///
+    /// :rtype: List[List[float]]
+    fn to_list(&self) -> PyResult<PyObject> {
+        create_matrix2x3_pylist(self._piston)
+    }
+
+    /// trans(x, y) -> Matrix2d
+    ///
+    /// Translate by ``(x, y)``
+    ///
+    /// :rtype: Matrix2d
+    fn trans(&self, x: f64, y: f64) -> PyResult<Matrix2d> {
+        Ok(self._piston.trans(x, y).into())
+    }
+
+    /// rot_rad(angle) -> Matrix2d
+    ///
+    /// Rotate by an angle in radians
+    ///
+    /// :rtype: Matrix2d
+    fn rot_rad(&self, angle: f64) -> PyResult<Matrix2d> {
+        Ok(self._piston.rot_rad(angle).into())
+    }
+
+    /// rot_deg(angle) -> Matrix2d
+    ///
+    /// Rotate by an angle in degrees
+    ///
+    /// :rtype: Matrix2d
+    fn rot_deg(&self, angle: f64) -> PyResult<Matrix2d> {
+        Ok(self._piston.rot_deg(angle).into())
+    }
+
+    /// orient(x, y) -> Matrix2d
+    ///
+    /// Rotate so the x axis points along the vector ``(x, y)``
+    ///
+    /// :rtype: Matrix2d
+    fn orient(&self, x: f64, y: f64) -> PyResult<Matrix2d> {
+        Ok(self._piston.orient(x, y).into())
+    }
+
+    /// scale(sx, sy) -> Matrix2d
+    ///
+    /// Scale by ``sx`` horizontally and ``sy`` vertically
+    ///
+    /// :rtype: Matrix2d
+    fn scale(&self, sx: f64, sy: f64) -> PyResult<Matrix2d> {
+        Ok(self._piston.scale(sx, sy).into())
+    }
+
+    /// zoom(s) -> Matrix2d
+    ///
+    /// Scale by ``s`` in both directions
+    ///
+    /// :rtype: Matrix2d
+    fn zoom(&self, s: f64) -> PyResult<Matrix2d> {
+        Ok(self._piston.zoom(s).into())
+    }
+
+    /// shear(x, y) -> Matrix2d
+    ///
+    /// Shear by ``x`` horizontally and ``y`` vertically
+    ///
+    /// :rtype: Matrix2d
+    fn shear(&self, x: f64, y: f64) -> PyResult<Matrix2d> {
+        Ok(self._piston.shear(x, y).into())
+    }
+
+    /// flip_h() -> Matrix2d
+    ///
+    /// Flip horizontally
+    ///
+    /// :rtype: Matrix2d
+    fn flip_h(&self) -> PyResult<Matrix2d> {
+        Ok(self._piston.flip_h().into())
+    }
+
+    /// flip_v() -> Matrix2d
+    ///
+    /// Flip vertically
+    ///
+    /// :rtype: Matrix2d
+    fn flip_v(&self) -> PyResult<Matrix2d> {
+        Ok(self._piston.flip_v().into())
+    }
+
+    /// flip_hv() -> Matrix2d
+    ///
+    /// Flip horizontally and vertically
+    ///
+    /// :rtype: Matrix2d
+    fn flip_hv(&self) -> PyResult<Matrix2d> {
+        Ok(self._piston.flip_hv().into())
+    }
+
+    /// append_transform(transform) -> Matrix2d
+    ///
+    /// Apply ``transform`` before this matrix
+    ///
+    /// :rtype: Matrix2d
+    fn append_transform(&self, transform: Transform) -> PyResult<Matrix2d> {
+        let transform = matrix2x3(Some(transform))?;
+
+        Ok(self._piston.append_transform(transform).into())
+    }
+
+    /// prepend_transform(transform) -> Matrix2d
+    ///
+    /// Apply ``transform`` after this matrix
+    ///
+    /// :rtype: Matrix2d
+    fn prepend_transform(&self, transform: Transform) -> PyResult<Matrix2d> {
+        let transform = matrix2x3(Some(transform))?;
+
+        Ok(self._piston.prepend_transform(transform).into())
+    }
+
+    /// invert() -> Matrix2d
+    ///
+    /// The inverse of this matrix
+    ///
+    /// :raises ValueError: If the matrix cannot be inverted
+    ///
+    /// :rtype: Matrix2d
+    fn invert(&self) -> PyResult<Matrix2d> {
+        let m = self._piston;
+        if m[0][0] * m[1][1] - m[0][1] * m[1][0] == 0.0 {
+            return Err(PyValueError::new_err("matrix is not invertible"));
+        }
+
+        Ok(math::invert(m).into())
+    }
+
+    /// transform_pos(x, y) -> Tuple[float, float]
+    ///
+    /// Apply the matrix to a point
+    ///
+    /// :rtype: Tuple[float, float]
+    fn transform_pos(&self, x: f64, y: f64) -> PyResult<[f64; 2]> {
+        Ok(math::transform_pos(self._piston, [x, y]))
+    }
+}
+
+#[pyproto]
+impl PyNumberProtocol for Matrix2d {
+    fn __mul__(lhs: &PyAny, rhs: &PyAny) -> PyResult<PyObject> {
+        let py = lhs.py();
+        // Other operands are left to Python, which tries their reflected
+        // operation before raising TypeError
+        match (
+            lhs.extract::<PyRef<Matrix2d>>(),
+            rhs.extract::<PyRef<Matrix2d>>(),
+        ) {
+            (Ok(lhs), Ok(rhs)) => {
+                Ok(Matrix2d::from(lhs._piston.append_transform(rhs._piston)).into_py(py))
+            }
+            _ => Ok(py.NotImplemented()),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for Matrix2d {
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(format!("Matrix2d({:?})", self._piston))
+    }
+
+    fn __richcmp__(&self, other: PyRef<Matrix2d>, op: CompareOp) -> PyResult<PyObject> {
+        let py = other.py();
+        Ok(match op {
+            CompareOp::Eq => (self._piston == other._piston).into_py(py),
+            CompareOp::Ne => (self._piston != other._piston).into_py(py),
+            _ => py.NotImplemented(),
+        })
+    }
+}
+
+/// How shapes are blended with what is already drawn, and which pixels they
+/// may draw to.
+///
+/// Like :class:`Matrix2d`, every operation returns a new draw state. Pass one
+/// as ``draw_state`` to any of the drawing functions.
+///
+/// Clipping to a shape takes two passes. First draw the shape with
+/// :func:`DrawState.new_clip`, which writes to the stencil buffer instead of
+/// the screen, then draw with :func:`DrawState.new_inside` (or
+/// :func:`DrawState.new_outside`) to only touch the pixels inside (or outside)
+/// of it. Clear the stencil buffer with ``clear_stencil(0)`` first.
+#[pyclass(module = "piston2d.graphics")]
+#[derive(Clone)]
+pub struct DrawState {
+    pub _piston: PistonDrawState,
+}
+
+impl From<PistonDrawState> for DrawState {
+    fn from(draw_state: PistonDrawState) -> Self {
+        DrawState {
+            _piston: draw_state,
+        }
+    }
+}
+
+#[pymethods]
+impl DrawState {
+    /// DrawState() -> DrawState
+    ///
+    /// The default draw state, with alpha blending and no clipping
+    #[new]
+    fn new() -> Self {
+        PistonDrawState::new_alpha().into()
+    }
+
+    /// new_alpha() -> DrawState
+    ///
+    /// Draw with alpha blending, the same as ``DrawState()``
+    ///
+    /// :rtype: DrawState
+    #[staticmethod]
+    fn new_alpha() -> PyResult<DrawState> {
+        Ok(PistonDrawState::new_alpha().into())
+    }
+
+    /// new_clip() -> DrawState
+    ///
+    /// Draw to the stencil buffer with the value 255, instead of to the screen
+    ///
+    /// :rtype: DrawState
+    #[staticmethod]
+    fn new_clip() -> PyResult<DrawState> {
+        Ok(PistonDrawState::new_clip().into())
+    }
+
+    /// new_increment() -> DrawState
+    ///
+    /// Increase the stencil buffer, instead of drawing to the screen. This can
+    /// be used for nested clipping
+    ///
+    /// :rtype: DrawState
+    #[staticmethod]
+    fn new_increment() -> PyResult<DrawState> {
+        Ok(PistonDrawState::new_increment().into())
+    }
+
+    /// new_inside() -> DrawState
+    ///
+    /// Only draw where the stencil buffer is 255
+    ///
+    /// :rtype: DrawState
+    #[staticmethod]
+    fn new_inside() -> PyResult<DrawState> {
+        Ok(PistonDrawState::new_inside().into())
+    }
+
+    /// new_outside() -> DrawState
+    ///
+    /// Only draw where the stencil buffer is not 255
+    ///
+    /// :rtype: DrawState
+    #[staticmethod]
+    fn new_outside() -> PyResult<DrawState> {
+        Ok(PistonDrawState::new_outside().into())
+    }
+
+    /// scissor(rect) -> DrawState
+    ///
+    /// Only draw inside a rect, in pixels from the upper left corner of the
+    /// window
+    ///
+    /// :param rect: The rect, or None to draw anywhere
+    ///
+    /// :type rect: Optional[Tuple[int, int, int, int]]
+    ///
+    /// :rtype: DrawState
+    fn scissor(&self, rect: Option<[u32; 4]>) -> PyResult<DrawState> {
+        Ok(PistonDrawState {
+            scissor: rect,
+            ..self._piston
+        }
+        .into())
+    }
+
+    /// blend(mode) -> DrawState
+    ///
+    /// Change how colors are blended
+    ///
+    /// :param mode: One of ``"alpha"``, ``"add"``, ``"lighter"`` (add with
+    ///     alpha), ``"multiply"`` or ``"invert"``, or None to overwrite pixels
+    ///
+    /// :type mode: Optional[str]
+    ///
+    /// :rtype: DrawState
+    fn blend(&self, mode: Option<&str>) -> PyResult<DrawState> {
+        Ok(PistonDrawState {
+            blend: mode.map(parse_blend).transpose()?,
+            ..self._piston
+        }
+        .into())
+    }
+
+    /// stencil(mode, value=255) -> DrawState
+    ///
+    /// Change how the stencil buffer is used
+    ///
+    /// :param mode: One of ``"clip"``, ``"inside"``, ``"outside"`` or
+    ///     ``"increment"``, or None to not use the stencil buffer
+    ///
+    /// :type mode: Optional[str]
+    ///
+    /// :param value: The stencil value to write or test against
+    ///
+    /// :type value: int
+    ///
+    /// :rtype: DrawState
+    #[args(value = "255")]
+    fn stencil(&self, mode: Option<&str>, value: u8) -> PyResult<DrawState> {
+        Ok(PistonDrawState {
+            stencil: mode.map(|m| parse_stencil(m, value)).transpose()?,
+            ..self._piston
+        }
+        .into())
+    }
+
+    /// The rect drawing is limited to
+    ///
+    /// :type: Optional[Tuple[int, int, int, int]]
+    #[getter]
+    fn scissor_rect(&self) -> PyResult<Option<[u32; 4]>> {
+        Ok(self._piston.scissor)
+    }
+
+    /// The name of the blend mode
+    ///
+    /// :type: Optional[str]
+    #[getter]
+    fn blend_mode(&self) -> PyResult<Option<&'static str>> {
+        Ok(self._piston.blend.map(blend_name))
+    }
+
+    /// The name of the stencil mode
+    ///
+    /// :type: Optional[str]
+    #[getter]
+    fn stencil_mode(&self) -> PyResult<Option<&'static str>> {
+        Ok(self._piston.stencil.map(stencil_name))
+    }
+
+    /// The stencil value written or tested against, if any
+    ///
+    /// :type: Optional[int]
+    #[getter]
+    fn stencil_value(&self) -> PyResult<Option<u8>> {
+        Ok(match self._piston.stencil {
+            Some(Stencil::Clip(value))
+            | Some(Stencil::Inside(value))
+            | Some(Stencil::Outside(value)) => Some(value),
+            _ => None,
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for DrawState {
+    fn __repr__(&self) -> PyResult<String> {
+        let scissor = match self._piston.scissor {
+            Some(rect) => format!("{:?}", rect),
+            None => "None".to_string(),
+        };
+        let stencil = match self._piston.stencil {
+            Some(stencil) => format!("'{}'", stencil_name(stencil)),
+            None => "None".to_string(),
+        };
+        let blend = match self._piston.blend {
+            Some(blend) => format!("'{}'", blend_name(blend)),
+            None => "None".to_string(),
+        };
+
+        Ok(format!(
+            "DrawState(scissor={}, stencil={}, blend={})",
+            scissor, stencil, blend
+        ))
+    }
+
+    fn __richcmp__(&self, other: PyRef<DrawState>, op: CompareOp) -> PyResult<PyObject> {
+        let py = other.py();
+        Ok(match op {
+            CompareOp::Eq => (self._piston == other._piston).into_py(py),
+            CompareOp::Ne => (self._piston != other._piston).into_py(py),
+            _ => py.NotImplemented(),
+        })
+    }
+}
+
 /// Context of a draw loop
 #[pyclass(module = "piston2d.graphics")]
 #[derive(Clone)]
@@ -68,6 +675,12 @@ pub struct Context {
     pub _piston: PistonContext,
 }
 
+impl From<PistonContext> for Context {
+    fn from(context: PistonContext) -> Self {
+        Context { _piston: context }
+    }
+}
+
 #[pymethods]
 impl Context {
     /// Get the current viewport
@@ -101,23 +714,168 @@ impl Context {
         Ok(create_matrix2x3_pylist(self._piston.transform)?)
     }
 
-    // #[getter]
-    // fn draw_state(&self) -> PyResult<()> {
-    //     Ok(())
-    // }
+    /// The draw state of this context
+    ///
+    /// :type: DrawState
+    #[getter]
+    fn draw_state(&self) -> PyResult<DrawState> {
+        Ok(self._piston.draw_state.into())
+    }
+
+    /// reset() -> Context
+    ///
+    /// A copy of this context with the transformation reset to the default
+    /// coordinate system, with the origin in the center and the y axis pointing
+    /// up
+    ///
+    /// :rtype: Context
+    fn reset(&self) -> PyResult<Context> {
+        Ok(self._piston.reset().into())
+    }
+
+    /// store_view() -> Context
+    ///
+    /// A copy of this context with the current transformation stored as the
+    /// view
+    ///
+    /// :rtype: Context
+    fn store_view(&self) -> PyResult<Context> {
+        Ok(self._piston.store_view().into())
+    }
+
+    /// view_transform() -> Context
+    ///
+    /// A copy of this context with the transformation set to the view, with the
+    /// origin in the upper left corner and the y axis pointing down
+    ///
+    /// :rtype: Context
+    fn view_transform(&self) -> PyResult<Context> {
+        Ok(self._piston.view().into())
+    }
+
+    /// trans(x, y) -> Context
+    ///
+    /// A copy of this context translated by ``(x, y)``
+    ///
+    /// :rtype: Context
+    fn trans(&self, x: f64, y: f64) -> PyResult<Context> {
+        Ok(self._piston.trans(x, y).into())
+    }
+
+    /// rot_rad(angle) -> Context
+    ///
+    /// A copy of this context rotated by an angle in radians
+    ///
+    /// :rtype: Context
+    fn rot_rad(&self, angle: f64) -> PyResult<Context> {
+        Ok(self._piston.rot_rad(angle).into())
+    }
+
+    /// rot_deg(angle) -> Context
+    ///
+    /// A copy of this context rotated by an angle in degrees
+    ///
+    /// :rtype: Context
+    fn rot_deg(&self, angle: f64) -> PyResult<Context> {
+        Ok(self._piston.rot_deg(angle).into())
+    }
+
+    /// orient(x, y) -> Context
+    ///
+    /// A copy of this context rotated so the x axis points along ``(x, y)``
+    ///
+    /// :rtype: Context
+    fn orient(&self, x: f64, y: f64) -> PyResult<Context> {
+        Ok(self._piston.orient(x, y).into())
+    }
+
+    /// scale(sx, sy) -> Context
+    ///
+    /// A copy of this context scaled by ``sx`` horizontally and ``sy``
+    /// vertically
+    ///
+    /// :rtype: Context
+    fn scale(&self, sx: f64, sy: f64) -> PyResult<Context> {
+        Ok(self._piston.scale(sx, sy).into())
+    }
+
+    /// zoom(s) -> Context
+    ///
+    /// A copy of this context scaled by ``s`` in both directions
+    ///
+    /// :rtype: Context
+    fn zoom(&self, s: f64) -> PyResult<Context> {
+        Ok(self._piston.zoom(s).into())
+    }
+
+    /// shear(x, y) -> Context
+    ///
+    /// A copy of this context sheared by ``x`` horizontally and ``y`` vertically
+    ///
+    /// :rtype: Context
+    fn shear(&self, x: f64, y: f64) -> PyResult<Context> {
+        Ok(self._piston.shear(x, y).into())
+    }
+
+    /// flip_h() -> Context
+    ///
+    /// A copy of this context flipped horizontally
+    ///
+    /// :rtype: Context
+    fn flip_h(&self) -> PyResult<Context> {
+        Ok(self._piston.flip_h().into())
+    }
+
+    /// flip_v() -> Context
+    ///
+    /// A copy of this context flipped vertically
+    ///
+    /// :rtype: Context
+    fn flip_v(&self) -> PyResult<Context> {
+        Ok(self._piston.flip_v().into())
+    }
+
+    /// flip_hv() -> Context
+    ///
+    /// A copy of this context flipped horizontally and vertically
+    ///
+    /// :rtype: Context
+    fn flip_hv(&self) -> PyResult<Context> {
+        Ok(self._piston.flip_hv().into())
+    }
 
-    /// Reset the current transformation to the default
-    fn reset(&self) -> PyResult<()> {
-        self._piston.reset();
+    /// append_transform(transform) -> Context
+    ///
+    /// A copy of this context with ``transform`` applied before the current
+    /// transformation
+    ///
+    /// :type transform: Union[Matrix2d, List[List[float]]]
+    /// :rtype: Context
+    fn append_transform(&self, transform: Transform) -> PyResult<Context> {
+        let transform = matrix2x3(Some(transform))?;
 
-        Ok(())
+        Ok(self._piston.append_transform(transform).into())
     }
 
-    /// Store the current transformation as a new view
-    fn store_view(&self) -> PyResult<()> {
-        self._piston.store_view();
+    /// prepend_transform(transform) -> Context
+    ///
+    /// A copy of this context with ``transform`` applied after the current
+    /// transformation
+    ///
+    /// :type transform: Union[Matrix2d, List[List[float]]]
+    /// :rtype: Context
+    fn prepend_transform(&self, transform: Transform) -> PyResult<Context> {
+        let transform = matrix2x3(Some(transform))?;
 
-        Ok(())
+        Ok(self._piston.prepend_transform(transform).into())
+    }
+
+    /// The current transformation as a :class:`Matrix2d`
+    ///
+    /// :type: Matrix2d
+    #[getter]
+    fn matrix(&self) -> PyResult<Matrix2d> {
+        Ok(self._piston.transform.into())
     }
 
     /// The current view size
@@ -129,9 +887,9 @@ impl Context {
     }
 }
 
-/// rectangle(color, rect, transform, g) -> None
+/// rectangle(color, rect, transform, g, draw_state=None) -> None
 ///
-/// Draws a rectangle to the Opengl Graphics backend.
+/// Draws a rectangle to a graphics backend.
 ///
 /// :param color: The RGBA color with values between 0.0 and 1.0
 ///
@@ -143,26 +901,38 @@ impl Context {
 ///
 /// :param transform: The color, a list of length 4 that is
 ///
-/// :type transform: Tuple[Tuple[float, float, float], Tuple[float, float, float]]
+/// :type transform: Union[Matrix2d, List[List[float]]]
+///
+/// :param g: The graphics backend
 ///
-/// :param g: The GlGraphics instance
+/// :type g: Union[GlGraphics, SoftwareGraphics]
 ///
-/// :type g: GlGraphics
-#[pyfunction(rectangle, module = "piston2d.graphics")]
+/// :param draw_state: The draw state, for blending and clipping
+///
+/// :type draw_state: Optional[DrawState]
+#[pyfunction(rectangle, module = "piston2d.graphics", draw_state = "None")]
 pub fn rectangle(
     color: [f32; 4],
     rect: [f64; 4],
-    transform: Option<&PyList>,
-    g: &mut GlGraphics,
+    transform: Option<Transform>,
+    g: Backend,
+    draw_state: Option<PyRef<DrawState>>,
 ) -> PyResult<()> {
-    piston_rectangle(color, rect, matrix2x3(transform)?, &mut g._piston);
+    let transform = matrix2x3(transform)?;
+    let draw_state = piston_draw_state(draw_state);
+    with_backend!(g, |g| Rectangle::new(color).draw(
+        rect,
+        &draw_state,
+        transform,
+        g
+    ));
 
     Ok(())
 }
 
-/// circle_arc(color, radius, start, end, rect, transform, g) -> None
+/// circle_arc(color, radius, start, end, rect, transform, g, draw_state=None) -> None
 ///
-/// Draws a circle arc to the Opengl Graphics backend.
+/// Draws a circle arc to a graphics backend.
 ///
 /// :param radius: The radius of the circle arc
 ///
@@ -186,38 +956,480 @@ pub fn rectangle(
 ///
 /// :param transform: The color, a list of length 4 that is
 ///
-/// :type transform: Tuple[Tuple[float, float, float], Tuple[float, float, float]]
+/// :type transform: Union[Matrix2d, List[List[float]]]
+///
+/// :param g: The graphics backend
 ///
-/// :param g: The GlGraphics instance
+/// :type g: Union[GlGraphics, SoftwareGraphics]
 ///
-/// :type g: GlGraphics
-#[pyfunction(circle_arc, module = "piston2d.graphics")]
+/// :param draw_state: The draw state, for blending and clipping
+///
+/// :type draw_state: Optional[DrawState]
+#[pyfunction(circle_arc, module = "piston2d.graphics", draw_state = "None")]
+#[allow(clippy::too_many_arguments)]
 pub fn circle_arc(
     color: [f32; 4],
     radius: f64,
     start: f64,
     end: f64,
     rect: [f64; 4],
-    transform: Option<&PyList>,
-    g: &mut GlGraphics,
+    transform: Option<Transform>,
+    g: Backend,
+    draw_state: Option<PyRef<DrawState>>,
+) -> PyResult<()> {
+    let transform = matrix2x3(transform)?;
+    let draw_state = piston_draw_state(draw_state);
+    let arc = CircleArc::new(color, radius, start, end);
+    with_backend!(g, |g| arc.draw(rect, &draw_state, transform, g));
+
+    Ok(())
+}
+
+/// ellipse(color, rect, transform, g, draw_state=None) -> None
+///
+/// Draws an ellipse filling a rect to a graphics backend.
+///
+/// :param color: The RGBA color with values between 0.0 and 1.0
+///
+/// :type color: Tuple[float, float, float, float]
+///
+/// :param rect: The rect the ellipse fills
+///
+/// :type rect: Tuple[float, float, float, float]
+///
+/// :param transform: The transformation to draw with
+///
+/// :type transform: Union[Matrix2d, List[List[float]]]
+///
+/// :param g: The graphics backend
+///
+/// :type g: Union[GlGraphics, SoftwareGraphics]
+///
+/// :param draw_state: The draw state, for blending and clipping
+///
+/// :type draw_state: Optional[DrawState]
+#[pyfunction(ellipse, module = "piston2d.graphics", draw_state = "None")]
+pub fn ellipse(
+    color: [f32; 4],
+    rect: [f64; 4],
+    transform: Option<Transform>,
+    g: Backend,
+    draw_state: Option<PyRef<DrawState>>,
+) -> PyResult<()> {
+    let transform = matrix2x3(transform)?;
+    let draw_state = piston_draw_state(draw_state);
+    with_backend!(g, |g| Ellipse::new(color).draw(
+        rect,
+        &draw_state,
+        transform,
+        g
+    ));
+
+    Ok(())
+}
+
+/// line(color, radius, line, transform, g, shape="square", draw_state=None) -> None
+///
+/// Draws a line to a graphics backend.
+///
+/// :param color: The RGBA color with values between 0.0 and 1.0
+///
+/// :type color: Tuple[float, float, float, float]
+///
+/// :param radius: Half the thickness of the line
+///
+/// :type radius: float
+///
+/// :param line: The end points, as ``(x1, y1, x2, y2)``
+///
+/// :type line: Tuple[float, float, float, float]
+///
+/// :param transform: The transformation to draw with
+///
+/// :type transform: Union[Matrix2d, List[List[float]]]
+///
+/// :param g: The graphics backend
+///
+/// :type g: Union[GlGraphics, SoftwareGraphics]
+///
+/// :param shape: The end caps, ``"square"``, ``"round"`` or ``"bevel"``
+///
+/// :type shape: str
+///
+/// :param draw_state: The draw state, for blending and clipping
+///
+/// :type draw_state: Optional[DrawState]
+#[pyfunction(
+    line,
+    module = "piston2d.graphics",
+    shape = "\"square\"",
+    draw_state = "None"
+)]
+pub fn line(
+    color: [f32; 4],
+    radius: f64,
+    line: [f64; 4],
+    transform: Option<Transform>,
+    g: Backend,
+    shape: &str,
+    draw_state: Option<PyRef<DrawState>>,
 ) -> PyResult<()> {
-    piston_circle_arc(
-        color,
-        radius,
+    let transform = matrix2x3(transform)?;
+    let draw_state = piston_draw_state(draw_state);
+    let piston_line = Line::new(color, radius).shape(line_shape(shape)?);
+    with_backend!(g, |g| piston_line.draw(line, &draw_state, transform, g));
+
+    Ok(())
+}
+
+/// line_from_to(color, radius, start, end, transform, g, shape="square", draw_state=None) -> None
+///
+/// Draws a line between two points to a graphics backend.
+///
+/// :param color: The RGBA color with values between 0.0 and 1.0
+///
+/// :type color: Tuple[float, float, float, float]
+///
+/// :param radius: Half the thickness of the line
+///
+/// :type radius: float
+///
+/// :param start: The first end point
+///
+/// :type start: Tuple[float, float]
+///
+/// :param end: The second end point
+///
+/// :type end: Tuple[float, float]
+///
+/// :param transform: The transformation to draw with
+///
+/// :type transform: Union[Matrix2d, List[List[float]]]
+///
+/// :param g: The graphics backend
+///
+/// :type g: Union[GlGraphics, SoftwareGraphics]
+///
+/// :param shape: The end caps, ``"square"``, ``"round"`` or ``"bevel"``
+///
+/// :type shape: str
+///
+/// :param draw_state: The draw state, for blending and clipping
+///
+/// :type draw_state: Optional[DrawState]
+#[pyfunction(
+    line_from_to,
+    module = "piston2d.graphics",
+    shape = "\"square\"",
+    draw_state = "None"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn line_from_to(
+    color: [f32; 4],
+    radius: f64,
+    start: [f64; 2],
+    end: [f64; 2],
+    transform: Option<Transform>,
+    g: Backend,
+    shape: &str,
+    draw_state: Option<PyRef<DrawState>>,
+) -> PyResult<()> {
+    let transform = matrix2x3(transform)?;
+    let draw_state = piston_draw_state(draw_state);
+    let piston_line = Line::new(color, radius).shape(line_shape(shape)?);
+    with_backend!(g, |g| piston_line.draw_from_to(
         start,
         end,
-        rect,
-        matrix2x3(transform)?,
-        &mut g._piston,
-    );
+        &draw_state,
+        transform,
+        g
+    ));
+
+    Ok(())
+}
+
+/// polygon(color, points, transform, g, draw_state=None) -> None
+///
+/// Draws a filled polygon to a graphics backend.
+///
+/// :param color: The RGBA color with values between 0.0 and 1.0
+///
+/// :type color: Tuple[float, float, float, float]
+///
+/// :param points: The corners of the polygon, in order
+///
+/// :type points: List[Tuple[float, float]]
+///
+/// :param transform: The transformation to draw with
+///
+/// :type transform: Union[Matrix2d, List[List[float]]]
+///
+/// :param g: The graphics backend
+///
+/// :type g: Union[GlGraphics, SoftwareGraphics]
+///
+/// :param draw_state: The draw state, for blending and clipping
+///
+/// :type draw_state: Optional[DrawState]
+#[pyfunction(polygon, module = "piston2d.graphics", draw_state = "None")]
+pub fn polygon(
+    color: [f32; 4],
+    points: Vec<[f64; 2]>,
+    transform: Option<Transform>,
+    g: Backend,
+    draw_state: Option<PyRef<DrawState>>,
+) -> PyResult<()> {
+    let transform = matrix2x3(transform)?;
+    let draw_state = piston_draw_state(draw_state);
+    with_backend!(g, |g| Polygon::new(color).draw(
+        &points,
+        &draw_state,
+        transform,
+        g
+    ));
+
+    Ok(())
+}
+
+/// polyline(color, radius, points, transform, g, shape="round", draw_state=None) -> None
+///
+/// Draws connected line segments through a list of points to a graphics
+/// backend. Round caps are the default, so that the joints have no gaps.
+///
+/// :param color: The RGBA color with values between 0.0 and 1.0
+///
+/// :type color: Tuple[float, float, float, float]
+///
+/// :param radius: Half the thickness of the lines
+///
+/// :type radius: float
+///
+/// :param points: The points to connect, in order
+///
+/// :type points: List[Tuple[float, float]]
+///
+/// :param transform: The transformation to draw with
+///
+/// :type transform: Union[Matrix2d, List[List[float]]]
+///
+/// :param g: The graphics backend
+///
+/// :type g: Union[GlGraphics, SoftwareGraphics]
+///
+/// :param shape: The end caps, ``"square"``, ``"round"`` or ``"bevel"``
+///
+/// :type shape: str
+///
+/// :param draw_state: The draw state, for blending and clipping
+///
+/// :type draw_state: Optional[DrawState]
+#[pyfunction(
+    polyline,
+    module = "piston2d.graphics",
+    shape = "\"round\"",
+    draw_state = "None"
+)]
+pub fn polyline(
+    color: [f32; 4],
+    radius: f64,
+    points: Vec<[f64; 2]>,
+    transform: Option<Transform>,
+    g: Backend,
+    shape: &str,
+    draw_state: Option<PyRef<DrawState>>,
+) -> PyResult<()> {
+    let transform = matrix2x3(transform)?;
+    let draw_state = piston_draw_state(draw_state);
+    let piston_line = Line::new(color, radius).shape(line_shape(shape)?);
+    with_backend!(g, |g| {
+        for segment in points.windows(2) {
+            piston_line.draw_from_to(segment[0], segment[1], &draw_state, transform, g);
+        }
+    });
 
     Ok(())
 }
 
+/// image(texture, transform, g, draw_state=None) -> None
+///
+/// Draws a texture at its own size to a graphics backend.
+///
+/// :param texture: The texture, loaded for the same backend as ``g``
+///
+/// :type texture: Union[Texture, SoftwareTexture]
+///
+/// :param transform: The transformation to draw with
+///
+/// :type transform: Union[Matrix2d, List[List[float]]]
+///
+/// :param g: The graphics backend
+///
+/// :type g: Union[GlGraphics, SoftwareGraphics]
+///
+/// :param draw_state: The draw state, for blending and clipping
+///
+/// :type draw_state: Optional[DrawState]
+#[pyfunction(image, module = "piston2d.graphics", draw_state = "None")]
+pub fn image(
+    texture: BackendTexture,
+    transform: Option<Transform>,
+    g: Backend,
+    draw_state: Option<PyRef<DrawState>>,
+) -> PyResult<()> {
+    let transform = matrix2x3(transform)?;
+    let draw_state = piston_draw_state(draw_state);
+
+    with_backend_resource!(g, texture, BackendTexture, "texture", |g, texture| {
+        Image::new().draw(texture, &draw_state, transform, g)
+    })
+}
+
+/// image_src(texture, src_rect, rect, transform, g, draw_state=None) -> None
+///
+/// Draws part of a texture, such as one frame of a sprite sheet, to a
+/// graphics backend.
+///
+/// :param texture: The texture, loaded for the same backend as ``g``
+///
+/// :type texture: Union[Texture, SoftwareTexture]
+///
+/// :param src_rect: The part of the texture to draw, in texels
+///
+/// :type src_rect: Tuple[float, float, float, float]
+///
+/// :param rect: Where to draw it
+///
+/// :type rect: Tuple[float, float, float, float]
+///
+/// :param transform: The transformation to draw with
+///
+/// :type transform: Union[Matrix2d, List[List[float]]]
+///
+/// :param g: The graphics backend
+///
+/// :type g: Union[GlGraphics, SoftwareGraphics]
+///
+/// :param draw_state: The draw state, for blending and clipping
+///
+/// :type draw_state: Optional[DrawState]
+#[pyfunction(image_src, module = "piston2d.graphics", draw_state = "None")]
+pub fn image_src(
+    texture: BackendTexture,
+    src_rect: [f64; 4],
+    rect: [f64; 4],
+    transform: Option<Transform>,
+    g: Backend,
+    draw_state: Option<PyRef<DrawState>>,
+) -> PyResult<()> {
+    let transform = matrix2x3(transform)?;
+    let draw_state = piston_draw_state(draw_state);
+    let image = Image::new().src_rect(src_rect).rect(rect);
+
+    with_backend_resource!(g, texture, BackendTexture, "texture", |g, texture| {
+        image.draw(texture, &draw_state, transform, g)
+    })
+}
+
+/// text(color, font_size, string, cache, transform, g, draw_state=None) -> None
+///
+/// Draws a line of text to a graphics backend. The transform's origin is
+/// the left end of the text's baseline.
+///
+/// :param color: The RGBA color with values between 0.0 and 1.0
+///
+/// :type color: Tuple[float, float, float, float]
+///
+/// :param font_size: The font size in points
+///
+/// :type font_size: int
+///
+/// :param string: The text to draw
+///
+/// :type string: str
+///
+/// :param cache: The glyph cache, loaded for the same backend as ``g``
+///
+/// :type cache: Union[GlyphCache, SoftwareGlyphCache]
+///
+/// :param transform: The transformation to draw with
+///
+/// :type transform: Union[Matrix2d, List[List[float]]]
+///
+/// :param g: The graphics backend
+///
+/// :type g: Union[GlGraphics, SoftwareGraphics]
+///
+/// :param draw_state: The draw state, for blending and clipping
+///
+/// :type draw_state: Optional[DrawState]
+#[pyfunction(text, module = "piston2d.graphics", draw_state = "None")]
+pub fn text(
+    color: [f32; 4],
+    font_size: u32,
+    string: &str,
+    cache: BackendGlyphCache,
+    transform: Option<Transform>,
+    g: Backend,
+    draw_state: Option<PyRef<DrawState>>,
+) -> PyResult<()> {
+    let transform = matrix2x3(transform)?;
+    let draw_state = piston_draw_state(draw_state);
+    let piston_text = Text::new_color(color, font_size);
+
+    with_backend_resource!(g, cache, BackendGlyphCache, "glyph cache", |g, cache| {
+        piston_text.draw(string, cache, &draw_state, transform, g)
+    })?
+    .map_err(PyRuntimeError::new_err)
+}
+
+/// measure_text(font_size, string, cache) -> Tuple[float, float]
+///
+/// The width and height a line of text takes up when drawn with
+/// :func:`text`. The height covers the font's ascent and descent, so it is
+/// the same for every string at a given size.
+///
+/// :param font_size: The font size in points
+///
+/// :type font_size: int
+///
+/// :param string: The text to measure
+///
+/// :type string: str
+///
+/// :param cache: The glyph cache
+///
+/// :type cache: Union[GlyphCache, SoftwareGlyphCache]
+///
+/// :rtype: Tuple[float, float]
+#[pyfunction(measure_text, module = "piston2d.graphics")]
+pub fn measure_text(font_size: u32, string: &str, cache: BackendGlyphCache) -> PyResult<[f64; 2]> {
+    match cache {
+        BackendGlyphCache::Gl(mut cache) => {
+            // Measuring renders any glyphs not yet in the cache
+            opengl::require_context()?;
+            fonts::measure(&mut cache._piston, font_size, string)
+        }
+        BackendGlyphCache::Software(mut cache) => {
+            fonts::measure(&mut cache._piston, font_size, string)
+        }
+    }
+}
+
 pub fn init_submodule(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<Context>()?;
+    m.add_class::<Matrix2d>()?;
+    m.add_class::<DrawState>()?;
     m.add_function(wrap_pyfunction!(rectangle, m)?)?;
     m.add_function(wrap_pyfunction!(circle_arc, m)?)?;
+    m.add_function(wrap_pyfunction!(ellipse, m)?)?;
+    m.add_function(wrap_pyfunction!(line, m)?)?;
+    m.add_function(wrap_pyfunction!(line_from_to, m)?)?;
+    m.add_function(wrap_pyfunction!(polygon, m)?)?;
+    m.add_function(wrap_pyfunction!(polyline, m)?)?;
+    m.add_function(wrap_pyfunction!(image, m)?)?;
+    m.add_function(wrap_pyfunction!(image_src, m)?)?;
+    m.add_function(wrap_pyfunction!(text, m)?)?;
+    m.add_function(wrap_pyfunction!(measure_text, m)?)?;
 
     Ok(())
 }
@@ -0,0 +1,182 @@
+use std::{cell::RefCell, rc::Rc};
+
+use pyo3::{
+    exceptions::{PyException, PyTypeError},
+    prelude::*,
+    types::{PyDict, PyTuple},
+};
+
+/// The events a `Piston2dApp` can dispatch to handlers
+#[derive(Clone, Copy, PartialEq)]
+pub enum HandlerKind {
+    Start,
+    Exit,
+    Render,
+    Update,
+    Idle,
+    ButtonPress,
+    ButtonRelease,
+    KeyPress,
+    KeyRelease,
+    MousePress,
+    MouseRelease,
+    MouseMove,
+    MouseRelative,
+    Scroll,
+    Resize,
+    Focus,
+    Cursor,
+    Text,
+    Close,
+}
+
+/// Handlers registered on an app, shared with the :class:`Handler` handles
+/// so they can remove themselves, even while the app is dispatching
+#[derive(Default)]
+pub struct Registry {
+    next_id: u64,
+    handlers: Vec<(u64, HandlerKind, PyObject)>,
+}
+
+impl Registry {
+    /// The handlers for an event with their ids, in the order they were
+    /// registered
+    fn handlers(&self, kind: HandlerKind) -> Vec<(u64, PyObject)> {
+        self.handlers
+            .iter()
+            .filter(|(_, k, _)| *k == kind)
+            .map(|(id, _, function)| (*id, function.clone()))
+            .collect()
+    }
+
+    fn contains(&self, id: u64) -> bool {
+        self.handlers.iter().any(|(i, _, _)| *i == id)
+    }
+}
+
+/// Raise TypeError unless `function` can be called
+pub fn require_callable(py: Python, function: &PyObject) -> PyResult<()> {
+    if function.as_ref(py).is_callable() {
+        Ok(())
+    } else {
+        Err(PyTypeError::new_err(format!(
+            "handlers must be callable, not '{}'",
+            function.as_ref(py).get_type().name()?
+        )))
+    }
+}
+
+/// Register `function` for an event, returning its handle
+pub fn register(
+    py: Python,
+    registry: &Rc<RefCell<Registry>>,
+    kind: HandlerKind,
+    function: PyObject,
+) -> PyResult<Handler> {
+    require_callable(py, &function)?;
+
+    let mut handlers = registry.borrow_mut();
+    let id = handlers.next_id;
+    handlers.next_id += 1;
+    handlers.handlers.push((id, kind, function.clone()));
+
+    Ok(Handler {
+        id,
+        function,
+        registry: Rc::clone(registry),
+    })
+}
+
+/// Call every handler for an event with `args`
+pub fn dispatch(
+    py: Python,
+    registry: &Rc<RefCell<Registry>>,
+    error_handler: &Option<PyObject>,
+    kind: HandlerKind,
+    args: impl IntoPy<Py<PyTuple>> + Clone,
+) -> PyResult<()> {
+    // Copied first, so handlers can add and remove handlers
+    let handlers = registry.borrow().handlers(kind);
+    for (id, function) in handlers {
+        if registry.borrow().contains(id) {
+            handle_error(py, error_handler, function.call1(py, args.clone()))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Pass the exception from a handler to the error handler, if there is one,
+/// instead of raising it. Exceptions that are not an ``Exception`` (such as
+/// ``KeyboardInterrupt``) are always raised.
+pub fn handle_error(
+    py: Python,
+    error_handler: &Option<PyObject>,
+    result: PyResult<PyObject>,
+) -> PyResult<()> {
+    match (result, error_handler) {
+        (Ok(_), _) => Ok(()),
+        (Err(error), Some(error_handler)) if error.is_instance::<PyException>(py) => {
+            error_handler.call1(py, (error.instance(py),))?;
+            Ok(())
+        }
+        (Err(error), _) => Err(error),
+    }
+}
+
+/// A function registered as a handler on a :class:`Piston2dApp`.
+///
+/// Calling the handler calls the function, so registering with a decorator
+/// leaves the function usable.
+///
+/// Handlers must be plain functions or bound methods. A handler is not a
+/// descriptor, so decorating a method in a class body never binds it to an
+/// instance, and the app would call it without ``self``. Register the bound
+/// method instead, such as ``app.on_update(self.update)``.
+#[pyclass(unsendable, module = "piston2d")]
+pub struct Handler {
+    id: u64,
+    function: PyObject,
+    registry: Rc<RefCell<Registry>>,
+}
+
+#[pymethods]
+impl Handler {
+    #[call]
+    #[args(args = "*", kwargs = "**")]
+    fn __call__(&self, py: Python, args: &PyTuple, kwargs: Option<&PyDict>) -> PyResult<PyObject> {
+        self.function.call(py, args, kwargs)
+    }
+
+    /// The registered function
+    ///
+    /// :type: Callable
+    #[getter(__wrapped__)]
+    fn wrapped(&self) -> PyResult<PyObject> {
+        Ok(self.function.clone())
+    }
+
+    /// Whether the handler is still registered
+    ///
+    /// :type: bool
+    #[getter]
+    fn registered(&self) -> PyResult<bool> {
+        Ok(self.registry.borrow().contains(self.id))
+    }
+
+    /// remove() -> bool
+    ///
+    /// Stop calling this handler. Handlers may remove themselves while being
+    /// called.
+    ///
+    /// :returns: Whether the handler was still registered
+    ///
+    /// :rtype: bool
+    fn remove(&self) -> PyResult<bool> {
+        let mut registry = self.registry.borrow_mut();
+        let count = registry.handlers.len();
+        registry.handlers.retain(|(id, _, _)| *id != self.id);
+
+        Ok(registry.handlers.len() != count)
+    }
+}
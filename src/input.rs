@@ -1,6 +1,219 @@
-use pyo3::prelude::*;
+use std::{
+    collections::{hash_map::DefaultHasher, HashSet},
+    hash::{Hash, Hasher},
+};
 
-use piston::Button as PistonButton;
+use pyo3::{
+    basic::CompareOp, exceptions::PyTypeError, prelude::*, types::PyLong, PyNativeType,
+    PyNumberProtocol, PyObjectProtocol,
+};
+
+use piston::{
+    Button as PistonButton, ControllerButton as PistonControllerButton, Event as PistonEvent,
+    Key as PistonKey, MouseButton as PistonMouseButton, MouseCursorEvent, MouseScrollEvent,
+    PressEvent, ReleaseEvent, UpdateEvent,
+};
+
+/// Create a pyclass for a piston enum that converts to and from a ``u32``
+/// code, with a class attribute for each variant.
+///
+/// Variants were once plain codes, so they still convert to and compare equal
+/// to their code as an int.
+macro_rules! code_enum {
+    ($(#[$meta:meta])* $name:ident($piston:ident) { $($variant:ident),* $(,)? }) => {
+        $(#[$meta])*
+        #[pyclass(module = "piston2d.input")]
+        #[derive(Clone, Copy)]
+        pub struct $name {
+            pub _piston: $piston,
+        }
+
+        #[allow(non_upper_case_globals)]
+        #[pymethods]
+        impl $name {
+            $(
+                #[classattr]
+                const $variant: $name = $name {
+                    _piston: $piston::$variant,
+                };
+            )*
+
+            /// Get the variant with a code, which is ``Unknown`` for unknown
+            /// codes
+            #[new]
+            #[args(code)]
+            fn new(code: u32) -> Self {
+                $name {
+                    _piston: $piston::from(code),
+                }
+            }
+
+            /// The name of the variant
+            ///
+            /// :type: str
+            #[getter]
+            fn name(&self) -> PyResult<String> {
+                Ok(format!("{:?}", self._piston))
+            }
+
+            /// The code of the variant
+            ///
+            /// :type: int
+            #[getter]
+            fn code(&self) -> PyResult<u32> {
+                Ok(self._piston.into())
+            }
+        }
+
+        #[pyproto]
+        impl PyObjectProtocol for $name {
+            fn __repr__(&self) -> PyResult<String> {
+                Ok(format!(concat!(stringify!($name), ".{:?}"), self._piston))
+            }
+
+            fn __hash__(&self) -> PyResult<u64> {
+                Ok(u32::from(self._piston).into())
+            }
+
+            fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+                let py = other.py();
+                let code = u32::from(self._piston);
+                // Only ints compare with codes, as the variants of other
+                // classes have an index too but are never equal
+                let equal = if let Ok(other) = other.extract::<PyRef<$name>>() {
+                    u32::from(other._piston) == code
+                } else if other.is_instance::<PyLong>()? {
+                    other.extract::<u32>().map_or(false, |other| other == code)
+                } else {
+                    return Ok(py.NotImplemented());
+                };
+
+                Ok(match op {
+                    CompareOp::Eq => equal.into_py(py),
+                    CompareOp::Ne => (!equal).into_py(py),
+                    _ => py.NotImplemented(),
+                })
+            }
+        }
+
+        #[pyproto]
+        impl PyNumberProtocol for $name {
+            fn __int__(&self) -> PyResult<u32> {
+                Ok(self._piston.into())
+            }
+
+            fn __index__(&self) -> PyResult<u32> {
+                Ok(self._piston.into())
+            }
+        }
+
+        impl From<$piston> for $name {
+            fn from(piston: $piston) -> Self {
+                $name { _piston: piston }
+            }
+        }
+    };
+}
+
+code_enum! {
+    /// A keyboard key, such as ``Key.A`` or ``Key.Space``
+    Key(PistonKey) {
+        Unknown, Backspace, Tab, Return, Escape, Space, Exclaim, Quotedbl, Hash, Dollar, Percent,
+        Ampersand, Quote, LeftParen, RightParen, Asterisk, Plus, Comma, Minus, Period, Slash, D0,
+        D1, D2, D3, D4, D5, D6, D7, D8, D9, Colon, Semicolon, Less, Equals, Greater, Question, At,
+        LeftBracket, Backslash, RightBracket, Caret, Underscore, Backquote, A, B, C, D, E, F, G, H,
+        I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y, Z, Delete, CapsLock, F1, F2, F3, F4, F5,
+        F6, F7, F8, F9, F10, F11, F12, PrintScreen, ScrollLock, Pause, Insert, Home, PageUp, End,
+        PageDown, Right, Left, Down, Up, NumLockClear, NumPadDivide, NumPadMultiply, NumPadMinus,
+        NumPadPlus, NumPadEnter, NumPad1, NumPad2, NumPad3, NumPad4, NumPad5, NumPad6, NumPad7,
+        NumPad8, NumPad9, NumPad0, NumPadPeriod, Application, Power, NumPadEquals, F13, F14, F15,
+        F16, F17, F18, F19, F20, F21, F22, F23, F24, Execute, Help, Menu, Select, Stop, Again, Undo,
+        Cut, Copy, Paste, Find, Mute, VolumeUp, VolumeDown, NumPadComma, NumPadEqualsAS400,
+        AltErase, Sysreq, Cancel, Clear, Prior, Return2, Separator, Out, Oper, ClearAgain, CrSel,
+        ExSel, NumPad00, NumPad000, ThousandsSeparator, DecimalSeparator, CurrencyUnit,
+        CurrencySubUnit, NumPadLeftParen, NumPadRightParen, NumPadLeftBrace, NumPadRightBrace,
+        NumPadTab, NumPadBackspace, NumPadA, NumPadB, NumPadC, NumPadD, NumPadE, NumPadF, NumPadXor,
+        NumPadPower, NumPadPercent, NumPadLess, NumPadGreater, NumPadAmpersand, NumPadDblAmpersand,
+        NumPadVerticalBar, NumPadDblVerticalBar, NumPadColon, NumPadHash, NumPadSpace, NumPadAt,
+        NumPadExclam, NumPadMemStore, NumPadMemRecall, NumPadMemClear, NumPadMemAdd,
+        NumPadMemSubtract, NumPadMemMultiply, NumPadMemDivide, NumPadPlusMinus, NumPadClear,
+        NumPadClearEntry, NumPadBinary, NumPadOctal, NumPadDecimal, NumPadHexadecimal, LCtrl,
+        LShift, LAlt, LGui, RCtrl, RShift, RAlt, RGui, Mode, AudioNext, AudioPrev, AudioStop,
+        AudioPlay, AudioMute, MediaSelect, Www, Mail, Calculator, Computer, AcSearch, AcHome,
+        AcBack, AcForward, AcStop, AcRefresh, AcBookmarks, BrightnessDown, BrightnessUp,
+        DisplaySwitch, KbdIllumToggle, KbdIllumDown, KbdIllumUp, Eject, Sleep,
+    }
+}
+
+code_enum! {
+    /// A mouse button, such as ``MouseButton.Left``
+    MouseButton(PistonMouseButton) {
+        Unknown, Left, Right, Middle, X1, X2, Button6, Button7, Button8,
+    }
+}
+
+/// A button on a game controller
+#[pyclass(module = "piston2d.input")]
+#[derive(Clone, Copy)]
+pub struct ControllerButton {
+    pub _piston: PistonControllerButton,
+}
+
+#[pymethods]
+impl ControllerButton {
+    /// ControllerButton(id: int, button: int) -> ControllerButton
+    ///
+    /// :param id: The id of the controller
+    /// :type id: int
+    /// :param button: The button on the controller
+    /// :type button: int
+    #[new]
+    #[args(id, button)]
+    fn new(id: u32, button: u8) -> Self {
+        ControllerButton {
+            _piston: PistonControllerButton::new(id, button),
+        }
+    }
+
+    /// The id of the controller
+    ///
+    /// :type: int
+    #[getter]
+    fn id(&self) -> PyResult<u32> {
+        Ok(self._piston.id)
+    }
+
+    /// The button on the controller
+    ///
+    /// :type: int
+    #[getter]
+    fn button(&self) -> PyResult<u8> {
+        Ok(self._piston.button)
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for ControllerButton {
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(format!(
+            "ControllerButton(id={}, button={})",
+            self._piston.id, self._piston.button
+        ))
+    }
+
+    fn __hash__(&self) -> PyResult<u64> {
+        Ok(u64::from(self._piston.id) << 8 | u64::from(self._piston.button))
+    }
+
+    fn __richcmp__(&self, other: PyRef<ControllerButton>, op: CompareOp) -> PyResult<PyObject> {
+        let py = other.py();
+        Ok(match op {
+            CompareOp::Eq => (self._piston == other._piston).into_py(py),
+            CompareOp::Ne => (self._piston != other._piston).into_py(py),
+            _ => py.NotImplemented(),
+        })
+    }
+}
 
 #[pyclass(module = "piston2d.input")]
 pub struct Button {
@@ -57,6 +270,39 @@ impl Button {
         }
     }
 
+    /// The key, if the button is a keyboard button
+    ///
+    /// :type: Optional[Key]
+    #[getter]
+    fn key(&self) -> PyResult<Option<Key>> {
+        match self._piston {
+            PistonButton::Keyboard(key) => Ok(Some(key.into())),
+            _ => Ok(None),
+        }
+    }
+
+    /// The mouse button, if the button is a mouse button
+    ///
+    /// :type: Optional[MouseButton]
+    #[getter]
+    fn mouse(&self) -> PyResult<Option<MouseButton>> {
+        match self._piston {
+            PistonButton::Mouse(mouse_button) => Ok(Some(mouse_button.into())),
+            _ => Ok(None),
+        }
+    }
+
+    /// The controller button, if the button is a controller button
+    ///
+    /// :type: Optional[ControllerButton]
+    #[getter]
+    fn controller(&self) -> PyResult<Option<ControllerButton>> {
+        match self._piston {
+            PistonButton::Controller(button) => Ok(Some(ControllerButton { _piston: button })),
+            _ => Ok(None),
+        }
+    }
+
     /// value() -> Optional[int]
     ///
     /// The "value" of this button, regardless of type
@@ -64,8 +310,8 @@ impl Button {
     /// .. warning::
     ///
     ///     Due to the nature of this function, values for types may collide!
-    ///     It is instaed recommended to check the type of the button before
-    ///     trying to get its value.
+    ///     It is instead recommended to use :attr:`key`, :attr:`mouse` or
+    ///     :attr:`controller`.
     ///
     /// .. warning::
     ///
@@ -84,14 +330,159 @@ impl Button {
     }
 }
 
+#[pyproto]
+impl PyObjectProtocol for Button {
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(match self._piston {
+            PistonButton::Keyboard(key) => format!("Button(Key.{:?})", key),
+            PistonButton::Mouse(mouse_button) => format!("Button(MouseButton.{:?})", mouse_button),
+            PistonButton::Controller(button) => format!(
+                "Button(ControllerButton(id={}, button={}))",
+                button.id, button.button
+            ),
+            PistonButton::Hat(hat) => format!("Button({:?})", hat),
+        })
+    }
+
+    fn __hash__(&self) -> PyResult<u64> {
+        let mut hasher = DefaultHasher::new();
+        self._piston.hash(&mut hasher);
+        Ok(hasher.finish())
+    }
+
+    fn __richcmp__(&self, other: PyRef<Button>, op: CompareOp) -> PyResult<PyObject> {
+        let py = other.py();
+        Ok(match op {
+            CompareOp::Eq => (self._piston == other._piston).into_py(py),
+            CompareOp::Ne => (self._piston != other._piston).into_py(py),
+            _ => py.NotImplemented(),
+        })
+    }
+}
+
 impl From<PistonButton> for Button {
     fn from(pb: PistonButton) -> Self {
         Button { _piston: pb }
     }
 }
 
+/// A button given as a :class:`Button`, :class:`Key`, :class:`MouseButton`,
+/// :class:`ControllerButton` or by the value of a key
+#[derive(FromPyObject)]
+pub enum ButtonValue<'p> {
+    #[pyo3(annotation = "Button")]
+    Button(PyRef<'p, Button>),
+    #[pyo3(annotation = "Key")]
+    Key(PyRef<'p, Key>),
+    #[pyo3(annotation = "MouseButton")]
+    Mouse(PyRef<'p, MouseButton>),
+    #[pyo3(annotation = "ControllerButton")]
+    Controller(PyRef<'p, ControllerButton>),
+    #[pyo3(annotation = "int")]
+    Value(u32),
+}
+
+impl ButtonValue<'_> {
+    /// The button, with plain values being keys
+    pub fn button(&self) -> PistonButton {
+        match self {
+            ButtonValue::Button(button) => button._piston,
+            ButtonValue::Key(key) => PistonButton::Keyboard(key._piston),
+            ButtonValue::Mouse(mouse_button) => PistonButton::Mouse(mouse_button._piston),
+            ButtonValue::Controller(button) => PistonButton::Controller(button._piston),
+            ButtonValue::Value(value) => PistonButton::Keyboard(PistonKey::from(*value)),
+        }
+    }
+
+    /// The key, raising TypeError for other buttons
+    pub fn key(&self) -> PyResult<PistonKey> {
+        match self.button() {
+            PistonButton::Keyboard(key) => Ok(key),
+            _ => Err(PyTypeError::new_err("expected a key")),
+        }
+    }
+
+    /// The mouse button, with plain values being mouse buttons, raising
+    /// TypeError for other buttons
+    pub fn mouse_button(&self) -> PyResult<PistonMouseButton> {
+        match self {
+            ButtonValue::Value(value) => Ok(PistonMouseButton::from(*value)),
+            _ => match self.button() {
+                PistonButton::Mouse(mouse_button) => Ok(mouse_button),
+                _ => Err(PyTypeError::new_err("expected a mouse button")),
+            },
+        }
+    }
+}
+
+/// The keys and mouse buttons held down, and the cursor, following the
+/// events given to it
+#[derive(Default)]
+pub struct InputState {
+    pub keys: HashSet<PistonKey>,
+    pub mouse_buttons: HashSet<PistonMouseButton>,
+    pub cursor: Option<[f64; 2]>,
+
+    // Since the last update
+    pub pressed: HashSet<PistonButton>,
+    pub released: HashSet<PistonButton>,
+    pub scroll: [f64; 2],
+}
+
+impl InputState {
+    /// Track an event, before its handlers are called
+    pub fn event(&mut self, e: &PistonEvent) {
+        if let Some(button) = e.press_args() {
+            match button {
+                PistonButton::Keyboard(key) => {
+                    self.keys.insert(key);
+                }
+                PistonButton::Mouse(mouse_button) => {
+                    self.mouse_buttons.insert(mouse_button);
+                }
+                _ => {}
+            }
+            self.pressed.insert(button);
+        }
+
+        if let Some(button) = e.release_args() {
+            match button {
+                PistonButton::Keyboard(key) => {
+                    self.keys.remove(&key);
+                }
+                PistonButton::Mouse(mouse_button) => {
+                    self.mouse_buttons.remove(&mouse_button);
+                }
+                _ => {}
+            }
+            self.released.insert(button);
+        }
+
+        if let Some(pos) = e.mouse_cursor_args() {
+            self.cursor = Some(pos);
+        }
+
+        if let Some([dx, dy]) = e.mouse_scroll_args() {
+            self.scroll = [self.scroll[0] + dx, self.scroll[1] + dy];
+        }
+    }
+
+    /// Forget the presses, releases and scrolling once an event's handlers
+    /// have been called, if it was an update
+    pub fn handled(&mut self, e: &PistonEvent) {
+        if e.update_args().is_some() {
+            self.pressed.clear();
+            self.released.clear();
+            self.scroll = [0.0, 0.0];
+        }
+    }
+}
+
 pub fn init_submodule(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<Button>()?;
+    m.add_class::<Key>()?;
+    m.add_class::<MouseButton>()?;
+    m.add_class::<ControllerButton>()?;
 
     Ok(())
 }
@@ -1,127 +1,682 @@
 use glutin_window::GlutinWindow;
 use opengl_graphics::{GlGraphics as PistonGlGraphics, OpenGL};
+use piston::event_loop::{EventSettings as PistonEventSettings, Events as PistonEvents};
 use piston::{
-    event_loop::{EventSettings as PistonEventSettings, Events as PistonEvents},
-    Key,
-};
-use piston::{
-    AdvancedWindow, Button as PistonButton, PressEvent, ReleaseEvent, RenderEvent, UpdateEvent,
+    AdvancedWindow, Button as PistonButton, CloseEvent, CursorEvent, Event, FocusEvent, IdleEvent,
+    MouseCursorEvent, MouseRelativeEvent, MouseScrollEvent, PressEvent, ReleaseEvent, RenderEvent,
+    ResizeEvent, TextEvent, UpdateEvent, Window as PistonWindow,
     WindowSettings as PistonWindowSettings,
 };
 use pyo3::wrap_pyfunction;
-use pyo3::{prelude::*, wrap_pymodule};
-use std::collections::HashSet;
+use pyo3::{
+    exceptions::{PyKeyboardInterrupt, PyTypeError},
+    prelude::*,
+    types::PyTuple,
+    wrap_pymodule,
+};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use crate::{
+    errors::{WindowClosedError, WindowCreationError},
+    graphics::Context,
+    handlers::{Handler, HandlerKind, Registry},
+    input::{Button, ButtonValue, InputState, Key, MouseButton},
+    opengl::GlGraphics,
+    window::{
+        events::{EventSettings, RenderArgs, UpdateArgs},
+        OpenWindow, WindowSettings,
+    },
+};
 
+pub mod errors;
+mod fonts;
 pub mod graphics;
+mod handlers;
 pub mod input;
 pub mod opengl;
+mod pixels;
+pub mod software;
 pub mod window;
 
 static VERSION: &str = "0.1.6";
 
 #[pyclass(unsendable)]
 struct Piston2dApp {
-    gl: PistonGlGraphics, // OpenGL drawing backend.
-    window: GlutinWindow,
-    keys: HashSet<Key>,
-    events: PistonEvents,
-
-    render_handlers: Vec<PyObject>,
-    update_handlers: Vec<PyObject>,
+    gl: Py<GlGraphics>, // OpenGL drawing backend.
+    // Handlers may use the app while it is ticking, so nothing stays borrowed
+    window: RefCell<GlutinWindow>,
+    _open: OpenWindow,
+    input: RefCell<InputState>,
+    events: RefCell<PistonEvents>,
+    stopping: Cell<bool>,
+
+    handlers: Rc<RefCell<Registry>>,
+    error_handler: RefCell<Option<PyObject>>,
 }
 
-#[pymethods(module = "piston2d")]
 impl Piston2dApp {
-    pub fn tick(&mut self) -> PyResult<()> {
-        let e = self.events.next(&mut self.window).unwrap();
+    fn register(&self, kind: HandlerKind, callable: PyObject) -> PyResult<Handler> {
+        Python::with_gil(|py| handlers::register(py, &self.handlers, kind, callable))
+    }
+
+    fn dispatch(
+        &self,
+        py: Python,
+        kind: HandlerKind,
+        args: impl IntoPy<Py<PyTuple>> + Clone,
+    ) -> PyResult<()> {
+        // Copied, so handlers can change the error handler
+        let error_handler = self.error_handler.borrow().clone();
+        handlers::dispatch(py, &self.handlers, &error_handler, kind, args)
+    }
+
+    /// The next event, or None once the window has closed
+    fn next_event(&self) -> Option<Event> {
+        let mut window = self.window.borrow_mut();
+        self.events.borrow_mut().next(&mut *window)
+    }
+
+    /// Track the input from an event and call its handlers
+    fn handle_event(&self, py: Python, e: &Event) -> PyResult<()> {
+        self.input.borrow_mut().event(e);
+        let result = self.dispatch_event(py, e);
+        self.input.borrow_mut().handled(e);
+
+        result
+    }
+
+    /// Dispatch events until the window closes or the app is stopped
+    fn run_loop(&self, py: Python) -> PyResult<()> {
+        while !self.stopping.get() {
+            // Lets Ctrl+C raise KeyboardInterrupt
+            py.check_signals()?;
+
+            match self.next_event() {
+                Some(e) => self.handle_event(py, &e)?,
+                None => break,
+            }
+        }
 
+        Ok(())
+    }
+
+    /// Call the handlers for everything `e` describes
+    fn dispatch_event(&self, py: Python, e: &Event) -> PyResult<()> {
         if let Some(args) = e.render_args() {
-            // self.update(&args);
-            let render_handlers = &self.render_handlers;
-            self.gl.draw(args.viewport(), |_c, _gl| {
-                for function in render_handlers {
-                    Python::with_gil(|py| {
-                        function.call0(py).unwrap();
-                    })
-                }
-            })
-        }
-
-        if let Some(_args) = e.update_args() {
-            for handler in &self.update_handlers {
-                Python::with_gil(|py| {
-                    handler.call0(py).unwrap();
-                })
+            // The graphics must not stay borrowed while handlers draw with it
+            let context = self.gl.borrow_mut(py)._piston.draw_begin(args.viewport());
+            let context = Py::new(py, Context::from(context))?;
+            let args = Py::new(py, RenderArgs::from(args))?;
+
+            let result = self.dispatch(py, HandlerKind::Render, (context, self.gl.clone(), args));
+            self.gl.borrow_mut(py)._piston.draw_end();
+            result?;
+        }
+
+        if let Some(args) = e.update_args() {
+            let args = Py::new(py, UpdateArgs::from(args))?;
+            self.dispatch(py, HandlerKind::Update, (args,))?;
+        }
+
+        if let Some(args) = e.idle_args() {
+            self.dispatch(py, HandlerKind::Idle, (args.dt,))?;
+        }
+
+        if let Some(button) = e.press_args() {
+            let kind = match button {
+                PistonButton::Keyboard(_) => Some(HandlerKind::KeyPress),
+                PistonButton::Mouse(_) => Some(HandlerKind::MousePress),
+                _ => None,
+            };
+
+            let button = Py::new(py, Button::from(button))?;
+            self.dispatch(py, HandlerKind::ButtonPress, (button.clone(),))?;
+            if let Some(kind) = kind {
+                self.dispatch(py, kind, (button,))?;
             }
         }
 
-        if let Some(PistonButton::Keyboard(key)) = e.press_args() {
-            self.keys.insert(key);
+        if let Some(button) = e.release_args() {
+            let kind = match button {
+                PistonButton::Keyboard(_) => Some(HandlerKind::KeyRelease),
+                PistonButton::Mouse(_) => Some(HandlerKind::MouseRelease),
+                _ => None,
+            };
+
+            let button = Py::new(py, Button::from(button))?;
+            self.dispatch(py, HandlerKind::ButtonRelease, (button.clone(),))?;
+            if let Some(kind) = kind {
+                self.dispatch(py, kind, (button,))?;
+            }
         }
 
-        if let Some(PistonButton::Keyboard(key)) = e.release_args() {
-            self.keys.remove(&key);
+        if let Some([x, y]) = e.mouse_cursor_args() {
+            self.dispatch(py, HandlerKind::MouseMove, (x, y))?;
+        }
+
+        if let Some([dx, dy]) = e.mouse_relative_args() {
+            self.dispatch(py, HandlerKind::MouseRelative, (dx, dy))?;
+        }
+
+        if let Some([dx, dy]) = e.mouse_scroll_args() {
+            self.dispatch(py, HandlerKind::Scroll, (dx, dy))?;
+        }
+
+        if let Some(args) = e.resize_args() {
+            let [width, height] = args.window_size;
+            self.dispatch(py, HandlerKind::Resize, (width, height))?;
+        }
+
+        if let Some(focused) = e.focus_args() {
+            self.dispatch(py, HandlerKind::Focus, (focused,))?;
+        }
+
+        if let Some(inside) = e.cursor_args() {
+            self.dispatch(py, HandlerKind::Cursor, (inside,))?;
+        }
+
+        if let Some(text) = e.text_args() {
+            self.dispatch(py, HandlerKind::Text, (text,))?;
+        }
+
+        if e.close_args().is_some() {
+            self.dispatch(py, HandlerKind::Close, ())?;
         }
 
         Ok(())
     }
+}
 
-    pub fn render(&mut self, callable: PyObject) -> PyResult<()> {
-        Python::with_gil(|py| {
-            let function = callable.as_ref(py);
-            if function.is_callable() {
-                self.render_handlers.push(callable);
+#[pymethods(module = "piston2d")]
+impl Piston2dApp {
+    pub fn tick(&self) -> PyResult<()> {
+        let e = match self.next_event() {
+            Some(e) => e,
+            None => return Err(WindowClosedError::new_err("the window has been closed")),
+        };
+
+        Python::with_gil(|py| self.handle_event(py, &e))
+    }
+
+    /// is_key_down(key) -> bool
+    ///
+    /// Whether a key is held down
+    ///
+    /// :param key: The key, as a :class:`Key`, :class:`Button` or its value
+    ///
+    /// :type key: Union[Key, Button, int]
+    ///
+    /// :rtype: bool
+    pub fn is_key_down(&self, key: ButtonValue) -> PyResult<bool> {
+        Ok(self.input.borrow().keys.contains(&key.key()?))
+    }
+
+    /// keys_down() -> List[Key]
+    ///
+    /// The keys held down, ordered by code
+    ///
+    /// :rtype: List[Key]
+    pub fn keys_down(&self) -> PyResult<Vec<Key>> {
+        let mut keys: Vec<_> = self.input.borrow().keys.iter().copied().collect();
+        keys.sort_unstable();
+
+        Ok(keys.into_iter().map(Key::from).collect())
+    }
+
+    /// is_mouse_down(button) -> bool
+    ///
+    /// Whether a mouse button is held down
+    ///
+    /// :param button: The mouse button, as a :class:`MouseButton`,
+    ///     :class:`Button` or its value
+    ///
+    /// :type button: Union[MouseButton, Button, int]
+    ///
+    /// :rtype: bool
+    pub fn is_mouse_down(&self, button: ButtonValue) -> PyResult<bool> {
+        let button = button.mouse_button()?;
+
+        Ok(self.input.borrow().mouse_buttons.contains(&button))
+    }
+
+    /// mouse_buttons_down() -> List[MouseButton]
+    ///
+    /// The mouse buttons held down, ordered by code
+    ///
+    /// :rtype: List[MouseButton]
+    pub fn mouse_buttons_down(&self) -> PyResult<Vec<MouseButton>> {
+        let mut buttons: Vec<_> = self.input.borrow().mouse_buttons.iter().copied().collect();
+        buttons.sort_unstable();
+
+        Ok(buttons.into_iter().map(MouseButton::from).collect())
+    }
+
+    /// cursor_position() -> Optional[Tuple[float, float]]
+    ///
+    /// Where the mouse cursor is in the window, or None before it has moved
+    ///
+    /// :rtype: Optional[Tuple[float, float]]
+    pub fn cursor_position(&self) -> PyResult<Option<[f64; 2]>> {
+        Ok(self.input.borrow().cursor)
+    }
+
+    /// scroll_delta() -> Tuple[float, float]
+    ///
+    /// How far the mouse wheel or touchpad has scrolled since the last update
+    ///
+    /// :rtype: Tuple[float, float]
+    pub fn scroll_delta(&self) -> PyResult<[f64; 2]> {
+        Ok(self.input.borrow().scroll)
+    }
+
+    /// was_pressed(button) -> bool
+    ///
+    /// Whether a button was pressed since the last update. The update
+    /// handlers see the presses leading up to their update.
+    ///
+    /// :param button: The button, key, mouse button, or the value of a key
+    ///
+    /// :type button: Union[Button, Key, MouseButton, int]
+    ///
+    /// :rtype: bool
+    pub fn was_pressed(&self, button: ButtonValue) -> PyResult<bool> {
+        Ok(self.input.borrow().pressed.contains(&button.button()))
+    }
+
+    /// was_released(button) -> bool
+    ///
+    /// Whether a button was released since the last update. The update
+    /// handlers see the releases leading up to their update.
+    ///
+    /// :param button: The button, key, mouse button, or the value of a key
+    ///
+    /// :type button: Union[Button, Key, MouseButton, int]
+    ///
+    /// :rtype: bool
+    pub fn was_released(&self, button: ButtonValue) -> PyResult<bool> {
+        Ok(self.input.borrow().released.contains(&button.button()))
+    }
+
+    /// run()
+    ///
+    /// Handle events until the window closes or :func:`stop` is called. The
+    /// :func:`on_start` handlers are called first, and the :func:`on_exit`
+    /// handlers last, even if a handler raised an exception.
+    ///
+    /// Pressing Ctrl+C (``KeyboardInterrupt``) closes the window and returns.
+    pub fn run(&self, py: Python) -> PyResult<()> {
+        self.stopping.set(false);
+
+        let result = self
+            .dispatch(py, HandlerKind::Start, ())
+            .and_then(|_| self.run_loop(py));
+        let result = match result {
+            Err(error) if error.is_instance::<PyKeyboardInterrupt>(py) => {
+                self.window.borrow_mut().set_should_close(true);
+                Ok(())
             }
-        });
+            result => result,
+        };
+
+        let exit = self.dispatch(py, HandlerKind::Exit, ());
+        result.and(exit)
+    }
+
+    /// stop()
+    ///
+    /// Make :func:`run` return after the event being handled, leaving the
+    /// window open
+    pub fn stop(&self) -> PyResult<()> {
+        self.stopping.set(true);
 
         Ok(())
     }
 
-    pub fn update(&mut self, callable: PyObject) -> PyResult<()> {
+    /// on_start(callable) -> Handler
+    ///
+    /// Call ``callable()`` when :func:`run` starts. Usable as a decorator.
+    ///
+    /// :rtype: Handler
+    pub fn on_start(&self, callable: PyObject) -> PyResult<Handler> {
+        self.register(HandlerKind::Start, callable)
+    }
+
+    /// on_exit(callable) -> Handler
+    ///
+    /// Call ``callable()`` when :func:`run` returns. Usable as a decorator.
+    ///
+    /// :rtype: Handler
+    pub fn on_exit(&self, callable: PyObject) -> PyResult<Handler> {
+        self.register(HandlerKind::Exit, callable)
+    }
+
+    /// render(callable) -> Handler
+    ///
+    /// The same as :func:`on_render`
+    pub fn render(&self, callable: PyObject) -> PyResult<Handler> {
+        self.register(HandlerKind::Render, callable)
+    }
+
+    /// update(callable) -> Handler
+    ///
+    /// The same as :func:`on_update`
+    pub fn update(&self, callable: PyObject) -> PyResult<Handler> {
+        self.register(HandlerKind::Update, callable)
+    }
+
+    /// on_render(callable) -> Handler
+    ///
+    /// Call ``callable(context, graphics, args)`` to draw each frame, with a
+    /// :class:`Context`, the :class:`GlGraphics` and the :class:`RenderArgs`.
+    /// Usable as a decorator.
+    ///
+    /// :rtype: Handler
+    pub fn on_render(&self, callable: PyObject) -> PyResult<Handler> {
+        self.register(HandlerKind::Render, callable)
+    }
+
+    /// on_update(callable) -> Handler
+    ///
+    /// Call ``callable(args)`` on each update, with the :class:`UpdateArgs`.
+    /// Usable as a decorator.
+    ///
+    /// :rtype: Handler
+    pub fn on_update(&self, callable: PyObject) -> PyResult<Handler> {
+        self.register(HandlerKind::Update, callable)
+    }
+
+    /// on_idle(callable) -> Handler
+    ///
+    /// Call ``callable(dt)`` when the event loop is idle, with the expected
+    /// idle time in seconds. Usable as a decorator.
+    ///
+    /// :rtype: Handler
+    pub fn on_idle(&self, callable: PyObject) -> PyResult<Handler> {
+        self.register(HandlerKind::Idle, callable)
+    }
+
+    /// on_button_press(callable) -> Handler
+    ///
+    /// Call ``callable(button)`` when any :class:`Button` is pressed. Usable
+    /// as a decorator.
+    ///
+    /// :rtype: Handler
+    pub fn on_button_press(&self, callable: PyObject) -> PyResult<Handler> {
+        self.register(HandlerKind::ButtonPress, callable)
+    }
+
+    /// on_button_release(callable) -> Handler
+    ///
+    /// Call ``callable(button)`` when any :class:`Button` is released. Usable
+    /// as a decorator.
+    ///
+    /// :rtype: Handler
+    pub fn on_button_release(&self, callable: PyObject) -> PyResult<Handler> {
+        self.register(HandlerKind::ButtonRelease, callable)
+    }
+
+    /// on_key_press(callable) -> Handler
+    ///
+    /// Call ``callable(button)`` when a key is pressed. Usable as a decorator.
+    ///
+    /// :rtype: Handler
+    pub fn on_key_press(&self, callable: PyObject) -> PyResult<Handler> {
+        self.register(HandlerKind::KeyPress, callable)
+    }
+
+    /// on_key_release(callable) -> Handler
+    ///
+    /// Call ``callable(button)`` when a key is released. Usable as a
+    /// decorator.
+    ///
+    /// :rtype: Handler
+    pub fn on_key_release(&self, callable: PyObject) -> PyResult<Handler> {
+        self.register(HandlerKind::KeyRelease, callable)
+    }
+
+    /// on_mouse_press(callable) -> Handler
+    ///
+    /// Call ``callable(button)`` when a mouse button is pressed. Usable as a
+    /// decorator.
+    ///
+    /// :rtype: Handler
+    pub fn on_mouse_press(&self, callable: PyObject) -> PyResult<Handler> {
+        self.register(HandlerKind::MousePress, callable)
+    }
+
+    /// on_mouse_release(callable) -> Handler
+    ///
+    /// Call ``callable(button)`` when a mouse button is released. Usable as a
+    /// decorator.
+    ///
+    /// :rtype: Handler
+    pub fn on_mouse_release(&self, callable: PyObject) -> PyResult<Handler> {
+        self.register(HandlerKind::MouseRelease, callable)
+    }
+
+    /// on_mouse_move(callable) -> Handler
+    ///
+    /// Call ``callable(x, y)`` when the mouse cursor moves, with its position
+    /// in the window. Usable as a decorator.
+    ///
+    /// :rtype: Handler
+    pub fn on_mouse_move(&self, callable: PyObject) -> PyResult<Handler> {
+        self.register(HandlerKind::MouseMove, callable)
+    }
+
+    /// on_mouse_relative(callable) -> Handler
+    ///
+    /// Call ``callable(dx, dy)`` with relative mouse movement, which is not
+    /// limited by the edges of the window. Usable as a decorator.
+    ///
+    /// :rtype: Handler
+    pub fn on_mouse_relative(&self, callable: PyObject) -> PyResult<Handler> {
+        self.register(HandlerKind::MouseRelative, callable)
+    }
+
+    /// on_scroll(callable) -> Handler
+    ///
+    /// Call ``callable(dx, dy)`` when the mouse wheel or touchpad scrolls.
+    /// Usable as a decorator.
+    ///
+    /// :rtype: Handler
+    pub fn on_scroll(&self, callable: PyObject) -> PyResult<Handler> {
+        self.register(HandlerKind::Scroll, callable)
+    }
+
+    /// on_resize(callable) -> Handler
+    ///
+    /// Call ``callable(width, height)`` when the window is resized, with its
+    /// new size in points. Usable as a decorator.
+    ///
+    /// :rtype: Handler
+    pub fn on_resize(&self, callable: PyObject) -> PyResult<Handler> {
+        self.register(HandlerKind::Resize, callable)
+    }
+
+    /// on_focus(callable) -> Handler
+    ///
+    /// Call ``callable(focused)`` when the window gains or loses focus. Usable
+    /// as a decorator.
+    ///
+    /// :rtype: Handler
+    pub fn on_focus(&self, callable: PyObject) -> PyResult<Handler> {
+        self.register(HandlerKind::Focus, callable)
+    }
+
+    /// on_cursor(callable) -> Handler
+    ///
+    /// Call ``callable(inside)`` when the cursor enters or leaves the window.
+    /// Usable as a decorator.
+    ///
+    /// :rtype: Handler
+    pub fn on_cursor(&self, callable: PyObject) -> PyResult<Handler> {
+        self.register(HandlerKind::Cursor, callable)
+    }
+
+    /// on_text(callable) -> Handler
+    ///
+    /// Call ``callable(text)`` with text typed into the window. Usable as a
+    /// decorator.
+    ///
+    /// :rtype: Handler
+    pub fn on_text(&self, callable: PyObject) -> PyResult<Handler> {
+        self.register(HandlerKind::Text, callable)
+    }
+
+    /// on_close(callable) -> Handler
+    ///
+    /// Call ``callable()`` when the window is asked to close. Usable as a
+    /// decorator.
+    ///
+    /// :rtype: Handler
+    pub fn on_close(&self, callable: PyObject) -> PyResult<Handler> {
+        self.register(HandlerKind::Close, callable)
+    }
+
+    /// on_error(callable) -> Optional[Callable]
+    ///
+    /// Call ``callable`` with any exception raised by a handler, instead of
+    /// raising it from :func:`tick`. The remaining handlers still run. Pass
+    /// None to raise exceptions again.
+    ///
+    /// :param callable: The error handler
+    ///
+    /// :type callable: Optional[Callable[[Exception], None]]
+    pub fn on_error(&self, callable: Option<PyObject>) -> PyResult<Option<PyObject>> {
         Python::with_gil(|py| {
-            let function = callable.as_ref(py);
-            if function.is_callable() {
-                self.update_handlers.push(callable);
+            if let Some(function) = &callable {
+                handlers::require_callable(py, function)?;
             }
-        });
 
-        Ok(())
+            *self.error_handler.borrow_mut() = callable.clone();
+            Ok(callable)
+        })
     }
 
-    pub fn set_title(&mut self, title: String) -> PyResult<()> {
-        self.window.set_title(title);
+    pub fn set_title(&self, title: String) -> PyResult<()> {
+        self.window.borrow_mut().set_title(title);
 
         Ok(())
     }
 
-    pub fn set_size(&mut self, dimensions: [u32; 2]) -> PyResult<()> {
-        self.window.set_size(dimensions);
+    pub fn set_size(&self, dimensions: [u32; 2]) -> PyResult<()> {
+        self.window.borrow_mut().set_size(dimensions);
 
         Ok(())
     }
 }
 
-#[pyfunction(module = "piston2d")]
-fn init(title: &str, dimensions: [u32; 2]) -> PyResult<Piston2dApp> {
-    // Change this to OpenGL::V2_1 if not working.
-    let opengl = OpenGL::V3_2;
+/// Create the window for `init`, falling back from OpenGL 3.2 to 2.1 when no
+/// version was asked for and the 3.2 context could not be created
+fn create_app_window(
+    settings: PistonWindowSettings,
+    opengl: Option<OpenGL>,
+) -> PyResult<(GlutinWindow, OpenGL)> {
+    if let Some(opengl) = opengl {
+        let window = window::create_window(&settings.graphics_api(opengl))?;
+        return Ok((window, opengl));
+    }
 
-    // Create a Glutin window.
-    let window: GlutinWindow = PistonWindowSettings::new(title, dimensions)
-        .graphics_api(opengl)
-        .decorated(true)
-        .exit_on_esc(true)
-        .build()
-        .unwrap();
+    // Only a context that failed, such as for an unsupported version, is
+    // retried, as 2.1 would fail the same way for anything else
+    let error = match window::create_window(&settings.clone().graphics_api(OpenGL::V3_2)) {
+        Ok(window) => return Ok((window, OpenGL::V3_2)),
+        Err(failure) if failure.context => failure.message,
+        Err(failure) => return Err(failure.into()),
+    };
+    match window::create_window(&settings.graphics_api(OpenGL::V2_1)) {
+        Ok(window) => Ok((window, OpenGL::V2_1)),
+        Err(failure) => Err(WindowCreationError::new_err(format!(
+            "could not create the window with OpenGL 3.2 ({}) or 2.1 ({})",
+            error, failure.message
+        ))),
+    }
+}
 
-    Ok(Piston2dApp {
-        gl: PistonGlGraphics::new(opengl),
-        window: window,
-        keys: HashSet::new(),
-        events: PistonEvents::new(PistonEventSettings::new()),
+/// init(title=None, size=None, settings=None, event_settings=None, opengl=None) -> Piston2dApp
+///
+/// Create a window and an app to handle its events.
+///
+/// :param title: The window title, replacing the one in ``settings``
+///
+/// :type title: Optional[str]
+///
+/// :param size: The window size, replacing the one in ``settings``
+///
+/// :type size: Optional[Tuple[int, int]]
+///
+/// :param settings: The window settings. Without them, the window is
+///     decorated and closes when escape is pressed, and needs a title and size
+///
+/// :type settings: Optional[WindowSettings]
+///
+/// :param event_settings: The event loop settings
+///
+/// :type event_settings: Optional[EventSettings]
+///
+/// :param opengl: The OpenGL version, such as ``"3.2"``. By default 3.2 is
+///     tried first, then 2.1 if its OpenGL context could not be created
+///
+/// :type opengl: Optional[str]
+///
+/// :rtype: Piston2dApp
+#[pyfunction(
+    module = "piston2d",
+    title = "None",
+    size = "None",
+    settings = "None",
+    event_settings = "None",
+    opengl = "None"
+)]
+fn init(
+    py: Python,
+    title: Option<String>,
+    size: Option<[u32; 2]>,
+    settings: Option<WindowSettings>,
+    event_settings: Option<EventSettings>,
+    opengl: Option<&str>,
+) -> PyResult<Piston2dApp> {
+    let mut settings = match (settings, &title, size) {
+        (Some(settings), _, _) if settings.headless => {
+            return Err(WindowCreationError::new_err(
+                "Piston2dApp draws with OpenGL, so it cannot use a headless window",
+            ))
+        }
+        (Some(settings), _, _) => settings._piston,
+        (None, Some(title), Some(size)) => PistonWindowSettings::new(title.clone(), size)
+            .decorated(true)
+            .exit_on_esc(true),
+        (None, _, _) => {
+            return Err(PyTypeError::new_err(
+                "init needs a title and size, or WindowSettings",
+            ))
+        }
+    };
+    if let Some(title) = title {
+        settings.set_title(title);
+    }
+    if let Some(size) = size {
+        settings.set_size(size.into());
+    }
+
+    let opengl = opengl.map(opengl::parse_opengl).transpose()?;
+    let (window, opengl) = create_app_window(settings, opengl)?;
+    let event_settings = event_settings.map_or_else(PistonEventSettings::new, |e| e._piston);
 
-        render_handlers: vec![],
-        update_handlers: vec![],
+    Ok(Piston2dApp {
+        gl: Py::new(py, GlGraphics::from(PistonGlGraphics::new(opengl)))?,
+        window: RefCell::new(window),
+        _open: OpenWindow::open(),
+        input: RefCell::new(InputState::default()),
+        events: RefCell::new(PistonEvents::new(event_settings)),
+        stopping: Cell::new(false),
+
+        handlers: Rc::new(RefCell::new(Registry::default())),
+        error_handler: RefCell::new(None),
     })
 }
 
@@ -139,6 +694,31 @@ pub fn opengl(py: Python, m: &PyModule) -> PyResult<()> {
     Ok(())
 }
 
+/**
+Exceptions raised by piston2d, all subclasses of ``Piston2dError``
+
+- ``WindowCreationError``: a window could not be created, e.g. without a display
+- ``WindowClosedError``: the window has been closed, so there are no more events
+- ``InvalidTransformError``: a transform is not a 2x3 matrix of numbers
+- ``UnsupportedOpenGLVersion``: an OpenGL version string is not one piston knows
+*/
+#[pymodule]
+pub fn errors(py: Python, m: &PyModule) -> PyResult<()> {
+    errors::init_submodule(py, m)?;
+
+    Ok(())
+}
+
+/**
+Software-rasterized graphics backend, for drawing without a GPU or display
+*/
+#[pymodule]
+pub fn software(py: Python, m: &PyModule) -> PyResult<()> {
+    software::init_submodule(py, m)?;
+
+    Ok(())
+}
+
 /**
 Graphics module mirroring ``piston2d_graphics`` for Python built in Rust.
 
@@ -176,6 +756,7 @@ fn piston2d(_py: Python, m: &PyModule) -> PyResult<()> {
     // Add utils
     m.add_function(wrap_pyfunction!(init, m)?)?;
     m.add_class::<Piston2dApp>()?;
+    m.add_class::<Handler>()?;
 
     // Add window module
     m.add_wrapped(wrap_pymodule!(window))?;
@@ -186,6 +767,10 @@ fn piston2d(_py: Python, m: &PyModule) -> PyResult<()> {
     // Add graphics module
     m.add_wrapped(wrap_pymodule!(graphics))?;
     m.add_wrapped(wrap_pymodule!(opengl))?;
+    m.add_wrapped(wrap_pymodule!(software))?;
+
+    // Add errors module
+    m.add_wrapped(wrap_pymodule!(errors))?;
 
     m.add("__version__", VERSION)?;
 
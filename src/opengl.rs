@@ -1,26 +1,643 @@
-use graphics::Graphics;
-use opengl_graphics::{GlGraphics as PistonGlGraphics, OpenGL as PistonOpenGL};
-use pyo3::prelude::*;
-use std::str::FromStr;
+use gl::types::{GLint, GLsizei, GLuint};
+use graphics::{Graphics, ImageSize, Viewport as PistonViewport};
+use opengl_graphics::{
+    Filter, GlGraphics as PistonGlGraphics, GlyphCache as PistonGlyphCache, OpenGL as PistonOpenGL,
+    Texture as PistonTexture, TextureSettings as PistonTextureSettings, Wrap,
+};
+use pyo3::{
+    exceptions::{PyIOError, PyRuntimeError, PyValueError},
+    prelude::*,
+    types::PyBytes,
+};
+use std::{ptr, str::FromStr};
 
-use crate::{graphics::Context, window::events::Viewport};
+use crate::{
+    errors::UnsupportedOpenGLVersion,
+    fonts,
+    graphics::Context,
+    pixels,
+    window::{events::Viewport, Window},
+};
+
+/// Fail unless a window has loaded the OpenGL functions
+pub(crate) fn require_context() -> PyResult<()> {
+    if gl::GenTextures::is_loaded() && gl::GenFramebuffers::is_loaded() {
+        Ok(())
+    } else {
+        Err(PyRuntimeError::new_err(
+            "no OpenGL context, create a Window first",
+        ))
+    }
+}
+
+/// Parse an OpenGL version such as ``"3.2"``
+pub(crate) fn parse_opengl(version: &str) -> PyResult<PistonOpenGL> {
+    PistonOpenGL::from_str(version).map_err(|_| {
+        UnsupportedOpenGLVersion::new_err(format!(
+            "unsupported OpenGL version '{}', expected one of '2.0', '2.1', '3.0' to \
+             '3.3' or '4.0' to '4.5'",
+            version
+        ))
+    })
+}
+
+fn parse_filter(name: &str) -> PyResult<Filter> {
+    match name {
+        "linear" => Ok(Filter::Linear),
+        "nearest" => Ok(Filter::Nearest),
+        _ => Err(PyValueError::new_err(format!(
+            "unknown filter '{}', expected 'linear' or 'nearest'",
+            name
+        ))),
+    }
+}
+
+fn filter_name(filter: Filter) -> &'static str {
+    match filter {
+        Filter::Linear => "linear",
+        Filter::Nearest => "nearest",
+    }
+}
+
+fn parse_wrap(name: &str) -> PyResult<Wrap> {
+    match name {
+        "repeat" => Ok(Wrap::Repeat),
+        "mirrored_repeat" => Ok(Wrap::MirroredRepeat),
+        "clamp_to_edge" => Ok(Wrap::ClampToEdge),
+        "clamp_to_border" => Ok(Wrap::ClampToBorder),
+        _ => Err(PyValueError::new_err(format!(
+            "unknown wrap mode '{}', expected 'repeat', 'mirrored_repeat', \
+             'clamp_to_edge' or 'clamp_to_border'",
+            name
+        ))),
+    }
+}
+
+fn wrap_name(wrap: Wrap) -> &'static str {
+    match wrap {
+        Wrap::Repeat => "repeat",
+        Wrap::MirroredRepeat => "mirrored_repeat",
+        Wrap::ClampToEdge => "clamp_to_edge",
+        Wrap::ClampToBorder => "clamp_to_border",
+    }
+}
+
+/// Settings used when creating a :class:`Texture`
+#[pyclass(module = "piston2d.opengl")]
+#[derive(Clone)]
+pub struct TextureSettings {
+    pub _piston: PistonTextureSettings,
+}
+
+#[pymethods]
+impl TextureSettings {
+    /// TextureSettings() -> TextureSettings
+    ///
+    /// Create the default settings
+    #[new]
+    fn new() -> Self {
+        TextureSettings {
+            _piston: PistonTextureSettings::new(),
+        }
+    }
+
+    /// Whether to convert gamma, treated as sRGB color space
+    ///
+    /// :type: bool
+    #[getter]
+    fn get_convert_gamma(&self) -> PyResult<bool> {
+        Ok(self._piston.get_convert_gamma())
+    }
+
+    #[setter]
+    fn set_convert_gamma(&mut self, value: bool) -> PyResult<()> {
+        self._piston.set_convert_gamma(value);
+        Ok(())
+    }
+
+    /// Whether to compress on the GPU
+    ///
+    /// :type: bool
+    #[getter]
+    fn get_compress(&self) -> PyResult<bool> {
+        Ok(self._piston.get_compress())
+    }
+
+    #[setter]
+    fn set_compress(&mut self, value: bool) -> PyResult<()> {
+        self._piston.set_compress(value);
+        Ok(())
+    }
+
+    /// Whether to generate mipmap chain
+    ///
+    /// :type: bool
+    #[getter]
+    fn get_generate_mipmap(&self) -> PyResult<bool> {
+        Ok(self._piston.get_generate_mipmap())
+    }
+
+    #[setter]
+    fn set_generate_mipmap(&mut self, value: bool) -> PyResult<()> {
+        self._piston.set_generate_mipmap(value);
+        Ok(())
+    }
+
+    /// The minification filter, ``"linear"`` or ``"nearest"``
+    ///
+    /// :type: str
+    #[getter]
+    fn get_min(&self) -> PyResult<&'static str> {
+        Ok(filter_name(self._piston.get_min()))
+    }
+
+    #[setter]
+    fn set_min(&mut self, value: &str) -> PyResult<()> {
+        self._piston.set_min(parse_filter(value)?);
+        Ok(())
+    }
+
+    /// The magnification filter, ``"linear"`` or ``"nearest"``
+    ///
+    /// :type: str
+    #[getter]
+    fn get_mag(&self) -> PyResult<&'static str> {
+        Ok(filter_name(self._piston.get_mag()))
+    }
+
+    #[setter]
+    fn set_mag(&mut self, value: &str) -> PyResult<()> {
+        self._piston.set_mag(parse_filter(value)?);
+        Ok(())
+    }
+
+    /// The filter used between mipmap levels, ``"linear"`` or ``"nearest"``
+    ///
+    /// :type: str
+    #[getter]
+    fn get_mipmap(&self) -> PyResult<&'static str> {
+        Ok(filter_name(self._piston.get_mipmap()))
+    }
+
+    #[setter]
+    fn set_mipmap(&mut self, value: &str) -> PyResult<()> {
+        self._piston.set_mipmap(parse_filter(value)?);
+        Ok(())
+    }
+
+    /// Sets both the minification and magnification filter
+    ///
+    /// :type: str
+    #[setter]
+    fn set_filter(&mut self, value: &str) -> PyResult<()> {
+        self._piston.set_filter(parse_filter(value)?);
+        Ok(())
+    }
+
+    /// The horizontal wrap mode, one of ``"repeat"``, ``"mirrored_repeat"``,
+    /// ``"clamp_to_edge"`` or ``"clamp_to_border"``
+    ///
+    /// :type: str
+    #[getter]
+    fn get_wrap_u(&self) -> PyResult<&'static str> {
+        Ok(wrap_name(self._piston.get_wrap_u()))
+    }
+
+    #[setter]
+    fn set_wrap_u(&mut self, value: &str) -> PyResult<()> {
+        self._piston.set_wrap_u(parse_wrap(value)?);
+        Ok(())
+    }
+
+    /// The vertical wrap mode, one of ``"repeat"``, ``"mirrored_repeat"``,
+    /// ``"clamp_to_edge"`` or ``"clamp_to_border"``
+    ///
+    /// :type: str
+    #[getter]
+    fn get_wrap_v(&self) -> PyResult<&'static str> {
+        Ok(wrap_name(self._piston.get_wrap_v()))
+    }
+
+    #[setter]
+    fn set_wrap_v(&mut self, value: &str) -> PyResult<()> {
+        self._piston.set_wrap_v(parse_wrap(value)?);
+        Ok(())
+    }
+
+    /// The border color used by ``"clamp_to_border"``
+    ///
+    /// :type: Tuple[float, float, float, float]
+    #[getter]
+    fn get_border_color(&self) -> PyResult<[f32; 4]> {
+        Ok(self._piston.get_border_color())
+    }
+
+    #[setter]
+    fn set_border_color(&mut self, value: [f32; 4]) -> PyResult<()> {
+        self._piston.set_border_color(value);
+        Ok(())
+    }
+}
+
+/// A texture stored on the GPU, drawn with :func:`graphics.image`
+///
+/// .. note::
+///
+///     An OpenGL context must exist (i.e. a :class:`Window` must have been
+///     created) before a texture can be loaded.
+#[pyclass(module = "piston2d.opengl")]
+pub struct Texture {
+    pub _piston: PistonTexture,
+}
+
+#[pymethods]
+impl Texture {
+    /// from_path(path, settings=None) -> Texture
+    ///
+    /// Load a texture from an image file
+    ///
+    /// :param path: The image file to load
+    ///
+    /// :type path: str
+    ///
+    /// :param settings: The texture settings, or the defaults
+    ///
+    /// :type settings: Optional[TextureSettings]
+    ///
+    /// :rtype: Texture
+    #[staticmethod]
+    #[args(settings = "None")]
+    fn from_path(path: &str, settings: Option<TextureSettings>) -> PyResult<Self> {
+        require_context()?;
+        let settings = settings
+            .map(|s| s._piston)
+            .unwrap_or_else(PistonTextureSettings::new);
+
+        Ok(Texture {
+            _piston: PistonTexture::from_path(path, &settings).map_err(PyIOError::new_err)?,
+        })
+    }
+
+    /// from_bytes(data, width, height, settings=None) -> Texture
+    ///
+    /// Create a texture from raw RGBA bytes, row by row from the top
+    ///
+    /// :param data: ``width * height * 4`` bytes of pixel data
+    ///
+    /// :type data: bytes
+    ///
+    /// :param width: The width in pixels
+    ///
+    /// :type width: int
+    ///
+    /// :param height: The height in pixels
+    ///
+    /// :type height: int
+    ///
+    /// :param settings: The texture settings, or the defaults
+    ///
+    /// :type settings: Optional[TextureSettings]
+    ///
+    /// :rtype: Texture
+    #[staticmethod]
+    #[args(settings = "None")]
+    fn from_bytes(
+        data: &[u8],
+        width: u32,
+        height: u32,
+        settings: Option<TextureSettings>,
+    ) -> PyResult<Self> {
+        require_context()?;
+        let settings = settings
+            .map(|s| s._piston)
+            .unwrap_or_else(PistonTextureSettings::new);
+        let image = pixels::rgba_image(data, width, height)?;
+
+        Ok(Texture {
+            _piston: PistonTexture::from_image(&image, &settings),
+        })
+    }
+
+    /// The size of the texture
+    ///
+    /// :type: Tuple[int, int]
+    #[getter]
+    fn size(&self) -> PyResult<(u32, u32)> {
+        Ok(self._piston.get_size())
+    }
+}
+
+/// A TrueType font and the glyphs rendered from it so far, drawn with
+/// :func:`graphics.text`
+///
+/// .. note::
+///
+///     Glyphs are uploaded as textures the first time they are drawn, so an
+///     OpenGL context must exist by then.
+#[pyclass(module = "piston2d.opengl")]
+pub struct GlyphCache {
+    pub _piston: PistonGlyphCache<'static>,
+}
+
+#[pymethods]
+impl GlyphCache {
+    /// from_path(path, settings=None) -> GlyphCache
+    ///
+    /// Load a font from a TrueType file
+    ///
+    /// :param path: The font file to load
+    ///
+    /// :type path: str
+    ///
+    /// :param settings: The settings for the glyph textures, or the defaults
+    ///
+    /// :type settings: Optional[TextureSettings]
+    ///
+    /// :rtype: GlyphCache
+    #[staticmethod]
+    #[args(settings = "None")]
+    fn from_path(path: &str, settings: Option<TextureSettings>) -> PyResult<Self> {
+        let settings = settings
+            .map(|s| s._piston)
+            .unwrap_or_else(PistonTextureSettings::new);
+
+        Ok(GlyphCache {
+            _piston: PistonGlyphCache::from_font(fonts::load_font(path)?, (), settings),
+        })
+    }
+
+    /// from_bytes(data, settings=None) -> GlyphCache
+    ///
+    /// Load a font from the contents of a TrueType file
+    ///
+    /// :param data: The font data
+    ///
+    /// :type data: bytes
+    ///
+    /// :param settings: The settings for the glyph textures, or the defaults
+    ///
+    /// :type settings: Optional[TextureSettings]
+    ///
+    /// :rtype: GlyphCache
+    #[staticmethod]
+    #[args(settings = "None")]
+    fn from_bytes(data: &[u8], settings: Option<TextureSettings>) -> PyResult<Self> {
+        let settings = settings
+            .map(|s| s._piston)
+            .unwrap_or_else(PistonTextureSettings::new);
+
+        Ok(GlyphCache {
+            _piston: PistonGlyphCache::from_font(fonts::font_from_bytes(data)?, (), settings),
+        })
+    }
+}
+
+/// An offscreen framebuffer that :class:`GlGraphics` can draw into instead of
+/// the window.
+///
+/// .. note::
+///
+///     An OpenGL context must exist (i.e. a :class:`Window` must have been
+///     created) before a render target can be made.
+#[pyclass(module = "piston2d.opengl")]
+pub struct RenderTarget {
+    framebuffer: GLuint,
+    texture: GLuint,
+    stencil: GLuint,
+    size: [u32; 2],
+}
+
+impl RenderTarget {
+    fn piston_viewport(&self) -> PistonViewport {
+        let [w, h] = self.size;
+
+        PistonViewport {
+            rect: [0, 0, w as i32, h as i32],
+            draw_size: [w, h],
+            window_size: [w as f64, h as f64],
+        }
+    }
+
+    /// Read back the color buffer, row by row from the top
+    fn read(&self) -> Vec<u8> {
+        let [w, h] = self.size;
+        let mut data = vec![0u8; w as usize * h as usize * 4];
+
+        unsafe {
+            gl::BindFramebuffer(gl::FRAMEBUFFER, self.framebuffer);
+            gl::PixelStorei(gl::PACK_ALIGNMENT, 1);
+            gl::ReadPixels(
+                0,
+                0,
+                w as GLsizei,
+                h as GLsizei,
+                gl::RGBA,
+                gl::UNSIGNED_BYTE,
+                data.as_mut_ptr() as *mut _,
+            );
+            gl::BindFramebuffer(gl::FRAMEBUFFER, 0);
+        }
+        pixels::flip_rows(&mut data, w);
+
+        data
+    }
+}
+
+impl Drop for RenderTarget {
+    fn drop(&mut self) {
+        unsafe {
+            gl::DeleteFramebuffers(1, &self.framebuffer);
+            gl::DeleteRenderbuffers(1, &self.stencil);
+            gl::DeleteTextures(1, &self.texture);
+        }
+    }
+}
+
+#[pymethods]
+impl RenderTarget {
+    /// RenderTarget(width: int, height: int) -> RenderTarget
+    ///
+    /// Create a framebuffer with an RGBA color buffer and a stencil buffer
+    #[new]
+    fn new(width: u32, height: u32) -> PyResult<Self> {
+        require_context()?;
+
+        let (w, h) = (width as GLsizei, height as GLsizei);
+        let mut target = RenderTarget {
+            framebuffer: 0,
+            texture: 0,
+            stencil: 0,
+            size: [width, height],
+        };
+
+        let status = unsafe {
+            gl::GenTextures(1, &mut target.texture);
+            gl::BindTexture(gl::TEXTURE_2D, target.texture);
+            gl::TexImage2D(
+                gl::TEXTURE_2D,
+                0,
+                gl::RGBA8 as GLint,
+                w,
+                h,
+                0,
+                gl::RGBA,
+                gl::UNSIGNED_BYTE,
+                ptr::null(),
+            );
+            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MIN_FILTER, gl::LINEAR as GLint);
+            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MAG_FILTER, gl::LINEAR as GLint);
+
+            gl::GenRenderbuffers(1, &mut target.stencil);
+            gl::BindRenderbuffer(gl::RENDERBUFFER, target.stencil);
+            gl::RenderbufferStorage(gl::RENDERBUFFER, gl::DEPTH24_STENCIL8, w, h);
+
+            gl::GenFramebuffers(1, &mut target.framebuffer);
+            gl::BindFramebuffer(gl::FRAMEBUFFER, target.framebuffer);
+            gl::FramebufferTexture2D(
+                gl::FRAMEBUFFER,
+                gl::COLOR_ATTACHMENT0,
+                gl::TEXTURE_2D,
+                target.texture,
+                0,
+            );
+            gl::FramebufferRenderbuffer(
+                gl::FRAMEBUFFER,
+                gl::DEPTH_STENCIL_ATTACHMENT,
+                gl::RENDERBUFFER,
+                target.stencil,
+            );
+
+            let status = gl::CheckFramebufferStatus(gl::FRAMEBUFFER);
+            gl::BindFramebuffer(gl::FRAMEBUFFER, 0);
+            status
+        };
+
+        if status != gl::FRAMEBUFFER_COMPLETE {
+            return Err(PyRuntimeError::new_err(format!(
+                "framebuffer is incomplete (status {:#x})",
+                status
+            )));
+        }
+
+        Ok(target)
+    }
+
+    /// The size of the render target
+    ///
+    /// :type: Tuple[int, int]
+    #[getter]
+    fn size(&self) -> PyResult<[u32; 2]> {
+        Ok(self.size)
+    }
+
+    /// viewport() -> Viewport
+    ///
+    /// A viewport covering the whole render target
+    ///
+    /// :rtype: Viewport
+    fn viewport(&self) -> PyResult<Viewport> {
+        Ok(Viewport {
+            _piston: self.piston_viewport(),
+        })
+    }
+
+    /// read_pixels() -> bytes
+    ///
+    /// Read back the contents of the render target as RGBA bytes, row by row
+    /// from the top.
+    ///
+    /// .. note::
+    ///
+    ///     Call :func:`GlGraphics.draw_end` first, so that every draw call has
+    ///     been flushed.
+    ///
+    /// :rtype: bytes
+    fn read_pixels(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new(py, &self.read()).into())
+    }
+
+    /// save_png(path)
+    ///
+    /// Save the contents of the render target to a PNG file
+    ///
+    /// :param path: The file to write
+    ///
+    /// :type path: str
+    fn save_png(&self, path: &str) -> PyResult<()> {
+        pixels::save_png(path, &self.read(), self.size[0], self.size[1])
+    }
+}
 
 /// ``GlGraphics`` implementation and bindings.
+///
+/// Each window has its own OpenGL context, so drawing to several windows
+/// needs a ``GlGraphics`` for each, created with its window.
 #[pyclass(module = "piston2d.opengl")]
 pub struct GlGraphics {
     pub _piston: PistonGlGraphics,
+
+    // Framebuffer bound by draw_begin, if not the window
+    target: Option<GLuint>,
+
+    // Window whose context is made current by draw_begin
+    window: Option<Py<Window>>,
+}
+
+impl From<PistonGlGraphics> for GlGraphics {
+    fn from(gl: PistonGlGraphics) -> Self {
+        GlGraphics {
+            _piston: gl,
+            target: None,
+            window: None,
+        }
+    }
 }
 
 // This does not implement draw. Instead Python handles that
 #[pymethods]
 impl GlGraphics {
+    /// GlGraphics(opengl: str, window: Optional[Window] = None) -> GlGraphics
+    ///
+    /// :param opengl: The OpenGL version, such as ``"3.2"``
+    ///
+    /// :type opengl: str
+    ///
+    /// :param window: The window to draw to. Its context is made current now
+    ///     and by each :meth:`draw_begin`. Without one, the current context is
+    ///     used.
+    ///
+    /// :type window: Optional[Window]
+    ///
+    /// :raises ValueError: If the window is headless
     #[new]
-    fn new(opengl: &str) -> Self {
-        let opengl = PistonOpenGL::from_str(opengl).unwrap();
+    #[args(opengl, window = "None")]
+    fn new(py: Python, opengl: &str, window: Option<Py<Window>>) -> PyResult<Self> {
+        let opengl = parse_opengl(opengl)?;
+        if let Some(window) = &window {
+            let mut window = window.try_borrow_mut(py)?;
+            if window._piston.is_headless() {
+                return Err(PyValueError::new_err(
+                    "headless windows have no OpenGL context",
+                ));
+            }
+            window._piston.make_current();
+        }
+        // Compiling the shaders needs the OpenGL functions
+        require_context()?;
 
-        GlGraphics {
+        Ok(GlGraphics {
             _piston: PistonGlGraphics::new(opengl),
-        }
+            target: None,
+            window,
+        })
+    }
+
+    /// The window drawn to, if one was given
+    ///
+    /// :type: Optional[Window]
+    #[getter]
+    fn window(&self, py: Python) -> PyResult<Option<Py<Window>>> {
+        Ok(self.window.as_ref().map(|window| window.clone_ref(py)))
     }
 
     /// clear_color(color)
@@ -49,19 +666,52 @@ impl GlGraphics {
         Ok(())
     }
 
-    /// draw_begin(viewport: Viewport) -> Context
+    /// draw_begin(viewport: Optional[Viewport] = None, target: Optional[RenderTarget] = None) -> Context
     ///
-    /// Setup that should be called at the start of a frame's draw call.
+    /// Setup that should be called at the start of a frame's draw call. If
+    /// the graphics were created with a window, its context is made current
+    /// first.
     ///
-    /// :param viewport: The viewport (usually collected from `Event.render_args`)
+    /// :param viewport: The viewport (usually collected from
+    ///     `Event.render_args`). Defaults to the whole render target when one
+    ///     is given.
     ///
-    /// :type viewport: Viewport
+    /// :type viewport: Optional[Viewport]
+    ///
+    /// :param target: The render target to draw into instead of the window
+    ///
+    /// :type target: Optional[RenderTarget]
     ///
     /// :returns: The current context
     ///
     /// :rtype: Context
-    fn draw_begin(&mut self, viewport: Viewport) -> PyResult<Context> {
-        let ctx = self._piston.draw_begin(viewport._piston);
+    #[args(viewport = "None", target = "None")]
+    fn draw_begin(
+        &mut self,
+        py: Python,
+        viewport: Option<Viewport>,
+        target: Option<PyRef<RenderTarget>>,
+    ) -> PyResult<Context> {
+        let viewport = match (viewport, &target) {
+            (Some(viewport), _) => viewport._piston,
+            (None, Some(target)) => target.piston_viewport(),
+            (None, None) => {
+                return Err(PyValueError::new_err(
+                    "draw_begin needs a viewport or a render target",
+                ))
+            }
+        };
+
+        if let Some(window) = &self.window {
+            window.try_borrow_mut(py)?._piston.make_current();
+        }
+
+        self.target = target.map(|target| target.framebuffer);
+        unsafe {
+            gl::BindFramebuffer(gl::FRAMEBUFFER, self.target.unwrap_or(0));
+        }
+
+        let ctx = self._piston.draw_begin(viewport);
 
         Ok(Context { _piston: ctx })
     }
@@ -72,6 +722,12 @@ impl GlGraphics {
     fn draw_end(&mut self) -> PyResult<()> {
         self._piston.draw_end();
 
+        if self.target.take().is_some() {
+            unsafe {
+                gl::BindFramebuffer(gl::FRAMEBUFFER, 0);
+            }
+        }
+
         Ok(())
     }
 
@@ -91,6 +747,10 @@ impl GlGraphics {
 
 pub fn init_submodule(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<GlGraphics>()?;
+    m.add_class::<RenderTarget>()?;
+    m.add_class::<Texture>()?;
+    m.add_class::<TextureSettings>()?;
+    m.add_class::<GlyphCache>()?;
 
     Ok(())
 }
@@ -0,0 +1,46 @@
+use image::RgbaImage;
+use pyo3::{
+    exceptions::{PyIOError, PyValueError},
+    prelude::*,
+};
+
+/// Wrap top-down RGBA bytes in an image, checking the length matches
+pub fn rgba_image(data: &[u8], width: u32, height: u32) -> PyResult<RgbaImage> {
+    RgbaImage::from_raw(width, height, data.to_vec()).ok_or_else(|| {
+        PyValueError::new_err(format!(
+            "expected {} bytes for a {}x{} RGBA image, got {}",
+            width as usize * height as usize * 4,
+            width,
+            height,
+            data.len()
+        ))
+    })
+}
+
+/// Load an image file as top-down RGBA pixels
+pub fn load_rgba(path: &str) -> PyResult<RgbaImage> {
+    image::open(path)
+        .map(|image| image.to_rgba8())
+        .map_err(|e| PyIOError::new_err(format!("could not load '{}': {}", path, e)))
+}
+
+/// Reverse the row order of an RGBA buffer, converting between OpenGL's
+/// bottom-up rows and the top-down rows used everywhere else
+pub fn flip_rows(data: &mut [u8], width: u32) {
+    let stride = width as usize * 4;
+    if stride == 0 {
+        return;
+    }
+
+    let rows = data.len() / stride;
+    for y in 0..rows / 2 {
+        let (top, bottom) = data.split_at_mut((rows - y - 1) * stride);
+        top[y * stride..(y + 1) * stride].swap_with_slice(&mut bottom[..stride]);
+    }
+}
+
+/// Write top-down RGBA bytes to a PNG file
+pub fn save_png(path: &str, data: &[u8], width: u32, height: u32) -> PyResult<()> {
+    image::save_buffer(path, data, width, height, image::ColorType::Rgba8)
+        .map_err(|e| PyIOError::new_err(format!("could not save '{}': {}", path, e)))
+}
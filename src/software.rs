@@ -0,0 +1,665 @@
+use graphics::{
+    draw_state::{Blend, Stencil},
+    glyph_cache::rusttype::GlyphCache,
+    types::Color,
+    Context as PistonContext, DrawState, Graphics, ImageSize, Viewport as PistonViewport,
+};
+use opengl_graphics::{CreateTexture, Format, TextureOp, TextureSettings, UpdateTexture};
+use pyo3::{exceptions::PyIndexError, prelude::*, types::PyBytes};
+
+use crate::{fonts, graphics::Context, pixels, window::events::Viewport};
+
+/// RGBA pixels stored in memory, used as the software backend's texture type
+#[derive(Clone)]
+pub struct Bitmap {
+    width: u32,
+    height: u32,
+    data: Vec<u8>,
+}
+
+impl Bitmap {
+    /// Create a texture from tightly packed RGBA bytes
+    pub fn new(width: u32, height: u32, data: Vec<u8>) -> Self {
+        Bitmap {
+            width,
+            height,
+            data,
+        }
+    }
+
+    /// Nearest-neighbour lookup, clamping the coordinates to the edges
+    fn sample(&self, uv: [f32; 2]) -> Color {
+        if self.width == 0 || self.height == 0 {
+            return [0.0; 4];
+        }
+
+        let x = ((uv[0] * self.width as f32) as i64).clamp(0, self.width as i64 - 1) as usize;
+        let y = ((uv[1] * self.height as f32) as i64).clamp(0, self.height as i64 - 1) as usize;
+        let i = (y * self.width as usize + x) * 4;
+
+        match self.data.get(i..i + 4) {
+            Some(px) => [
+                px[0] as f32 / 255.0,
+                px[1] as f32 / 255.0,
+                px[2] as f32 / 255.0,
+                px[3] as f32 / 255.0,
+            ],
+            None => [0.0; 4],
+        }
+    }
+}
+
+impl ImageSize for Bitmap {
+    fn get_size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+}
+
+impl TextureOp<()> for Bitmap {
+    type Error = String;
+}
+
+impl CreateTexture<()> for Bitmap {
+    fn create<S: Into<[u32; 2]>>(
+        _factory: &mut (),
+        _format: Format,
+        memory: &[u8],
+        size: S,
+        _settings: &TextureSettings,
+    ) -> Result<Self, String> {
+        let [width, height] = size.into();
+        let len = width as usize * height as usize * 4;
+        if memory.len() < len {
+            return Err(format!("expected {} bytes, got {}", len, memory.len()));
+        }
+
+        Ok(Bitmap::new(width, height, memory[..len].to_vec()))
+    }
+}
+
+impl UpdateTexture<()> for Bitmap {
+    fn update<O, S>(
+        &mut self,
+        _factory: &mut (),
+        _format: Format,
+        memory: &[u8],
+        offset: O,
+        size: S,
+    ) -> Result<(), String>
+    where
+        O: Into<[u32; 2]>,
+        S: Into<[u32; 2]>,
+    {
+        let [x, y] = offset.into();
+        let [width, height] = size.into();
+        if x + width > self.width || y + height > self.height {
+            return Err("update is outside of the texture".into());
+        }
+
+        let row = width as usize * 4;
+        for (i, src) in memory.chunks_exact(row).take(height as usize).enumerate() {
+            let start = ((y as usize + i) * self.width as usize + x as usize) * 4;
+            self.data[start..start + row].copy_from_slice(src);
+        }
+
+        Ok(())
+    }
+}
+
+/// RGBA pixel buffer implementing piston's ``Graphics`` trait on the CPU.
+///
+/// Rows are stored top to bottom. Triangles are rasterized as soon as they
+/// are submitted, so ``draw_end`` has nothing left to flush.
+pub struct Canvas {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+    stencil: Vec<u8>,
+    viewport: Option<PistonViewport>,
+}
+
+impl Canvas {
+    /// Create a canvas cleared to transparent black
+    pub fn new(width: u32, height: u32) -> Self {
+        let len = width as usize * height as usize;
+
+        Canvas {
+            width,
+            height,
+            pixels: vec![0; len * 4],
+            stencil: vec![0; len],
+            viewport: None,
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// The raw RGBA bytes, row by row from the top
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// The RGBA value of a single pixel, if it is inside the canvas
+    pub fn pixel(&self, x: u32, y: u32) -> Option<[u8; 4]> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        let i = (y as usize * self.width as usize + x as usize) * 4;
+        Some([
+            self.pixels[i],
+            self.pixels[i + 1],
+            self.pixels[i + 2],
+            self.pixels[i + 3],
+        ])
+    }
+
+    /// A viewport covering the whole canvas
+    pub fn full_viewport(&self) -> PistonViewport {
+        PistonViewport {
+            rect: [0, 0, self.width as i32, self.height as i32],
+            draw_size: [self.width, self.height],
+            window_size: [self.width as f64, self.height as f64],
+        }
+    }
+
+    /// Setup that should be called at the start of a frame's draw call.
+    pub fn draw_begin(&mut self, viewport: PistonViewport) -> PistonContext {
+        self.viewport = Some(viewport);
+
+        PistonContext::new_viewport(viewport)
+    }
+
+    /// Finalize draw calls.
+    pub fn draw_end(&mut self) {
+        self.viewport = None;
+    }
+
+    /// Map normalized device coordinates to pixel coordinates.
+    ///
+    /// Like ``glViewport``, the viewport rect has its origin in the lower left
+    /// corner, while the canvas rows run from the top.
+    fn to_pixel(&self, p: [f32; 2]) -> [f32; 2] {
+        let rect = match self.viewport {
+            Some(viewport) => viewport.rect,
+            None => [0, 0, self.width as i32, self.height as i32],
+        };
+
+        let x = rect[0] as f32 + (p[0] + 1.0) * 0.5 * rect[2] as f32;
+        let y = rect[1] as f32 + (p[1] + 1.0) * 0.5 * rect[3] as f32;
+
+        [x, self.height as f32 - y]
+    }
+
+    /// Rasterize one triangle, calling `shade` with the barycentric weights of
+    /// every covered pixel centre.
+    fn fill_triangle<S>(&mut self, draw_state: &DrawState, triangle: [[f32; 2]; 3], mut shade: S)
+    where
+        S: FnMut([f32; 3]) -> Color,
+    {
+        let [a, b, c] = [
+            self.to_pixel(triangle[0]),
+            self.to_pixel(triangle[1]),
+            self.to_pixel(triangle[2]),
+        ];
+
+        let area = edge(a, b, c);
+        if area == 0.0 || !area.is_finite() {
+            return;
+        }
+
+        let mut bounds = [0, 0, self.width, self.height];
+        if let Some([x, y, w, h]) = draw_state.scissor {
+            bounds = [
+                x.min(self.width),
+                y.min(self.height),
+                x.saturating_add(w).min(self.width),
+                y.saturating_add(h).min(self.height),
+            ];
+        }
+
+        let min_x = a[0].min(b[0]).min(c[0]).floor().max(bounds[0] as f32) as u32;
+        let min_y = a[1].min(b[1]).min(c[1]).floor().max(bounds[1] as f32) as u32;
+        let max_x = a[0].max(b[0]).max(c[0]).ceil().min(bounds[2] as f32) as u32;
+        let max_y = a[1].max(b[1]).max(c[1]).ceil().min(bounds[3] as f32) as u32;
+
+        for y in min_y..max_y {
+            for x in min_x..max_x {
+                let p = [x as f32 + 0.5, y as f32 + 0.5];
+                let weights = [
+                    edge(b, c, p) / area,
+                    edge(c, a, p) / area,
+                    edge(a, b, p) / area,
+                ];
+
+                if weights.iter().any(|w| *w < 0.0) {
+                    continue;
+                }
+
+                let color = shade(weights);
+                self.write(x, y, draw_state, color);
+            }
+        }
+    }
+
+    /// Write a single fragment, applying the stencil test and blend mode
+    fn write(&mut self, x: u32, y: u32, draw_state: &DrawState, color: Color) {
+        let index = y as usize * self.width as usize + x as usize;
+
+        match draw_state.stencil {
+            Some(Stencil::Increment) => {
+                self.stencil[index] = self.stencil[index].saturating_add(1);
+                return;
+            }
+            Some(Stencil::Clip(value)) => {
+                self.stencil[index] = value;
+                return;
+            }
+            Some(Stencil::Inside(value)) if self.stencil[index] != value => return,
+            Some(Stencil::Outside(value)) if self.stencil[index] == value => return,
+            _ => {}
+        }
+
+        let px = &mut self.pixels[index * 4..index * 4 + 4];
+        let dst = [
+            px[0] as f32 / 255.0,
+            px[1] as f32 / 255.0,
+            px[2] as f32 / 255.0,
+            px[3] as f32 / 255.0,
+        ];
+
+        let out = blend(draw_state.blend, color, dst);
+        for (channel, value) in px.iter_mut().zip(out.iter()) {
+            *channel = (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+        }
+    }
+}
+
+/// Twice the signed area of the triangle `a`, `b`, `p`
+fn edge(a: [f32; 2], b: [f32; 2], p: [f32; 2]) -> f32 {
+    (b[0] - a[0]) * (p[1] - a[1]) - (b[1] - a[1]) * (p[0] - a[0])
+}
+
+/// Combine a source and destination color the same way ``opengl_graphics``
+/// configures ``glBlendFuncSeparate`` for each mode
+fn blend(mode: Option<Blend>, src: Color, dst: Color) -> Color {
+    let [sr, sg, sb, sa] = src;
+    let [dr, dg, db, da] = dst;
+
+    match mode {
+        None => src,
+        Some(Blend::Alpha) => [
+            sr * sa + dr * (1.0 - sa),
+            sg * sa + dg * (1.0 - sa),
+            sb * sa + db * (1.0 - sa),
+            sa + da,
+        ],
+        Some(Blend::Add) => [sr + dr, sg + dg, sb + db, sa + da],
+        Some(Blend::Lighter) => [sr * sa + dr, sg * sa + dg, sb * sa + db, da],
+        Some(Blend::Multiply) => [sr * dr, sg * dg, sb * db, sa * da],
+        Some(Blend::Invert) => [sr - dr * sr, sg - dg * sg, sb - db * sb, da],
+    }
+}
+
+/// Interpolate per-vertex values with barycentric weights
+fn lerp3<const N: usize>(values: [[f32; N]; 3], weights: [f32; 3]) -> [f32; N] {
+    let mut out = [0.0; N];
+    for (i, value) in out.iter_mut().enumerate() {
+        *value = values[0][i] * weights[0] + values[1][i] * weights[1] + values[2][i] * weights[2];
+    }
+
+    out
+}
+
+fn multiply(a: Color, b: Color) -> Color {
+    [a[0] * b[0], a[1] * b[1], a[2] * b[2], a[3] * b[3]]
+}
+
+impl Graphics for Canvas {
+    type Texture = Bitmap;
+
+    fn clear_color(&mut self, color: Color) {
+        let px = color.map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8);
+        for chunk in self.pixels.chunks_exact_mut(4) {
+            chunk.copy_from_slice(&px);
+        }
+    }
+
+    fn clear_stencil(&mut self, value: u8) {
+        for s in self.stencil.iter_mut() {
+            *s = value;
+        }
+    }
+
+    fn tri_list<F>(&mut self, draw_state: &DrawState, color: &[f32; 4], mut f: F)
+    where
+        F: FnMut(&mut dyn FnMut(&[[f32; 2]])),
+    {
+        f(&mut |vertices: &[[f32; 2]]| {
+            for tri in vertices.chunks_exact(3) {
+                self.fill_triangle(draw_state, [tri[0], tri[1], tri[2]], |_| *color);
+            }
+        })
+    }
+
+    fn tri_list_c<F>(&mut self, draw_state: &DrawState, mut f: F)
+    where
+        F: FnMut(&mut dyn FnMut(&[[f32; 2]], &[[f32; 4]])),
+    {
+        f(&mut |vertices: &[[f32; 2]], colors: &[[f32; 4]]| {
+            for (tri, col) in vertices.chunks_exact(3).zip(colors.chunks_exact(3)) {
+                let col = [col[0], col[1], col[2]];
+                self.fill_triangle(draw_state, [tri[0], tri[1], tri[2]], |w| lerp3(col, w));
+            }
+        })
+    }
+
+    fn tri_list_uv<F>(
+        &mut self,
+        draw_state: &DrawState,
+        color: &[f32; 4],
+        texture: &Bitmap,
+        mut f: F,
+    ) where
+        F: FnMut(&mut dyn FnMut(&[[f32; 2]], &[[f32; 2]])),
+    {
+        f(&mut |vertices: &[[f32; 2]], uvs: &[[f32; 2]]| {
+            for (tri, uv) in vertices.chunks_exact(3).zip(uvs.chunks_exact(3)) {
+                let uv = [uv[0], uv[1], uv[2]];
+                self.fill_triangle(draw_state, [tri[0], tri[1], tri[2]], |w| {
+                    multiply(texture.sample(lerp3(uv, w)), *color)
+                });
+            }
+        })
+    }
+
+    fn tri_list_uv_c<F>(&mut self, draw_state: &DrawState, texture: &Bitmap, mut f: F)
+    where
+        F: FnMut(&mut dyn FnMut(&[[f32; 2]], &[[f32; 2]], &[[f32; 4]])),
+    {
+        f(
+            &mut |vertices: &[[f32; 2]], uvs: &[[f32; 2]], colors: &[[f32; 4]]| {
+                let triangles = vertices
+                    .chunks_exact(3)
+                    .zip(uvs.chunks_exact(3))
+                    .zip(colors.chunks_exact(3));
+
+                for ((tri, uv), col) in triangles {
+                    let uv = [uv[0], uv[1], uv[2]];
+                    let col = [col[0], col[1], col[2]];
+                    self.fill_triangle(draw_state, [tri[0], tri[1], tri[2]], |w| {
+                        multiply(texture.sample(lerp3(uv, w)), lerp3(col, w))
+                    });
+                }
+            },
+        )
+    }
+}
+
+/// Software-rasterized graphics backend, drawing into an in-memory RGBA
+/// buffer. Useful where no GPU or display is available.
+#[pyclass(module = "piston2d.software")]
+pub struct SoftwareGraphics {
+    pub _piston: Canvas,
+}
+
+#[pymethods]
+impl SoftwareGraphics {
+    /// SoftwareGraphics(width: int, height: int) -> SoftwareGraphics
+    ///
+    /// Create a backend with a transparent pixel buffer of the given size
+    #[new]
+    fn new(width: u32, height: u32) -> Self {
+        SoftwareGraphics {
+            _piston: Canvas::new(width, height),
+        }
+    }
+
+    /// The size of the pixel buffer
+    ///
+    /// :type: Tuple[int, int]
+    #[getter]
+    fn size(&self) -> PyResult<[u32; 2]> {
+        Ok([self._piston.width(), self._piston.height()])
+    }
+
+    /// viewport() -> Viewport
+    ///
+    /// A viewport covering the whole pixel buffer
+    ///
+    /// :rtype: Viewport
+    fn viewport(&self) -> PyResult<Viewport> {
+        Ok(Viewport {
+            _piston: self._piston.full_viewport(),
+        })
+    }
+
+    /// clear_color(color)
+    ///
+    /// Clears the buffer with a certain color
+    ///
+    /// :param color: The color to use
+    ///
+    /// :type color: [Tuple[float, float, float, float]]
+    fn clear_color(&mut self, color: [f32; 4]) -> PyResult<()> {
+        self._piston.clear_color(color);
+
+        Ok(())
+    }
+
+    /// clear_stencil(value)
+    ///
+    /// Clears the stencil buffer
+    ///
+    /// :param value: The value to use
+    ///
+    /// :type value: int
+    fn clear_stencil(&mut self, value: u8) -> PyResult<()> {
+        self._piston.clear_stencil(value);
+
+        Ok(())
+    }
+
+    /// draw_begin(viewport: Optional[Viewport] = None) -> Context
+    ///
+    /// Setup that should be called at the start of a frame's draw call.
+    ///
+    /// :param viewport: The viewport, defaulting to the whole buffer
+    ///
+    /// :type viewport: Optional[Viewport]
+    ///
+    /// :returns: The current context
+    ///
+    /// :rtype: Context
+    #[args(viewport = "None")]
+    fn draw_begin(&mut self, viewport: Option<Viewport>) -> PyResult<Context> {
+        let viewport = match viewport {
+            Some(viewport) => viewport._piston,
+            None => self._piston.full_viewport(),
+        };
+
+        Ok(Context {
+            _piston: self._piston.draw_begin(viewport),
+        })
+    }
+
+    /// draw_end()
+    ///
+    /// Finalize draw calls.
+    fn draw_end(&mut self) -> PyResult<()> {
+        self._piston.draw_end();
+
+        Ok(())
+    }
+
+    /// get_pixel(x, y) -> Tuple[int, int, int, int]
+    ///
+    /// The RGBA value of a pixel, counting rows from the top
+    ///
+    /// :param x: The column
+    ///
+    /// :type x: int
+    ///
+    /// :param y: The row
+    ///
+    /// :type y: int
+    ///
+    /// :rtype: Tuple[int, int, int, int]
+    fn get_pixel(&self, x: u32, y: u32) -> PyResult<[u8; 4]> {
+        self._piston
+            .pixel(x, y)
+            .ok_or_else(|| PyIndexError::new_err("pixel out of range"))
+    }
+
+    /// read_pixels() -> bytes
+    ///
+    /// The contents of the buffer as RGBA bytes, row by row from the top
+    ///
+    /// :rtype: bytes
+    fn read_pixels(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new(py, self._piston.pixels()).into())
+    }
+
+    /// save_png(path)
+    ///
+    /// Save the contents of the buffer to a PNG file
+    ///
+    /// :param path: The file to write
+    ///
+    /// :type path: str
+    fn save_png(&self, path: &str) -> PyResult<()> {
+        pixels::save_png(
+            path,
+            self._piston.pixels(),
+            self._piston.width(),
+            self._piston.height(),
+        )
+    }
+}
+
+/// A texture for :class:`SoftwareGraphics`, drawn with :func:`graphics.image`.
+///
+/// .. note::
+///
+///     Textures are always sampled with the nearest texel, clamped to the
+///     edges.
+#[pyclass(module = "piston2d.software")]
+pub struct SoftwareTexture {
+    pub _piston: Bitmap,
+}
+
+#[pymethods]
+impl SoftwareTexture {
+    /// from_path(path) -> SoftwareTexture
+    ///
+    /// Load a texture from an image file
+    ///
+    /// :param path: The image file to load
+    ///
+    /// :type path: str
+    ///
+    /// :rtype: SoftwareTexture
+    #[staticmethod]
+    fn from_path(path: &str) -> PyResult<Self> {
+        let image = pixels::load_rgba(path)?;
+        let (width, height) = image.dimensions();
+
+        Ok(SoftwareTexture {
+            _piston: Bitmap::new(width, height, image.into_raw()),
+        })
+    }
+
+    /// from_bytes(data, width, height) -> SoftwareTexture
+    ///
+    /// Create a texture from raw RGBA bytes, row by row from the top
+    ///
+    /// :param data: ``width * height * 4`` bytes of pixel data
+    ///
+    /// :type data: bytes
+    ///
+    /// :param width: The width in pixels
+    ///
+    /// :type width: int
+    ///
+    /// :param height: The height in pixels
+    ///
+    /// :type height: int
+    ///
+    /// :rtype: SoftwareTexture
+    #[staticmethod]
+    fn from_bytes(data: &[u8], width: u32, height: u32) -> PyResult<Self> {
+        let image = pixels::rgba_image(data, width, height)?;
+
+        Ok(SoftwareTexture {
+            _piston: Bitmap::new(width, height, image.into_raw()),
+        })
+    }
+
+    /// The size of the texture
+    ///
+    /// :type: Tuple[int, int]
+    #[getter]
+    fn size(&self) -> PyResult<(u32, u32)> {
+        Ok(self._piston.get_size())
+    }
+}
+
+/// A TrueType font and the glyphs rendered from it so far, drawn on a
+/// :class:`SoftwareGraphics` with :func:`graphics.text`
+#[pyclass(module = "piston2d.software")]
+pub struct SoftwareGlyphCache {
+    pub _piston: GlyphCache<'static, (), Bitmap>,
+}
+
+#[pymethods]
+impl SoftwareGlyphCache {
+    /// from_path(path) -> SoftwareGlyphCache
+    ///
+    /// Load a font from a TrueType file
+    ///
+    /// :param path: The font file to load
+    ///
+    /// :type path: str
+    ///
+    /// :rtype: SoftwareGlyphCache
+    #[staticmethod]
+    fn from_path(path: &str) -> PyResult<Self> {
+        Ok(SoftwareGlyphCache {
+            _piston: GlyphCache::from_font(fonts::load_font(path)?, (), TextureSettings::new()),
+        })
+    }
+
+    /// from_bytes(data) -> SoftwareGlyphCache
+    ///
+    /// Load a font from the contents of a TrueType file
+    ///
+    /// :param data: The font data
+    ///
+    /// :type data: bytes
+    ///
+    /// :rtype: SoftwareGlyphCache
+    #[staticmethod]
+    fn from_bytes(data: &[u8]) -> PyResult<Self> {
+        Ok(SoftwareGlyphCache {
+            _piston: GlyphCache::from_font(
+                fonts::font_from_bytes(data)?,
+                (),
+                TextureSettings::new(),
+            ),
+        })
+    }
+}
+
+pub fn init_submodule(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_class::<SoftwareGraphics>()?;
+    m.add_class::<SoftwareTexture>()?;
+    m.add_class::<SoftwareGlyphCache>()?;
+
+    Ok(())
+}
@@ -1,32 +1,157 @@
-use std::time::Duration;
+use std::{
+    cell::RefCell,
+    error::Error,
+    panic::{self, AssertUnwindSafe},
+    rc::Rc,
+    sync::Arc,
+    thread,
+    time::Duration,
+};
 
-use pyo3::prelude::*;
+use pyo3::{exceptions::PyValueError, prelude::*};
 
+use glutin::{ContextError, CreationError};
 use glutin_window::GlutinWindow;
 use piston::{
-    AdvancedWindow, OpenGLWindow, Size, Window as PistonWindow,
-    WindowSettings as PistonWindowSettings,
+    AdvancedWindow, Size, Window as PistonWindow, WindowSettings as PistonWindowSettings,
 };
 
-use self::events::Event;
+use self::events::{new_event, Event};
+use crate::errors::WindowCreationError;
+
+pub use self::backend::{Backend, OpenWindow};
 
+mod backend;
 pub mod events;
 
+/// Why a glutin window could not be created
+pub(crate) struct CreationFailure {
+    pub message: String,
+    /// Whether the OpenGL context failed, such as for an unsupported version,
+    /// rather than the window itself
+    pub context: bool,
+}
+
+impl From<CreationFailure> for PyErr {
+    fn from(failure: CreationFailure) -> Self {
+        WindowCreationError::new_err(failure.message)
+    }
+}
+
+/// Whether glutin failed to create or make current the OpenGL context
+fn is_context_error(error: &(dyn Error + 'static)) -> bool {
+    if error.is::<ContextError>() {
+        return true;
+    }
+
+    match error.downcast_ref::<CreationError>() {
+        Some(CreationError::Window(_)) | Some(CreationError::NoBackendAvailable(_)) => false,
+        Some(CreationError::CreationErrors(errors)) => {
+            errors.iter().any(|error| is_context_error(&**error))
+        }
+        Some(_) => true,
+        None => false,
+    }
+}
+
+/// Create a glutin window, which converts to WindowCreationError if it fails.
+///
+/// winit panics instead of returning an error when there is no display, so
+/// that panic is caught (without printing it) and returned the same way.
+pub(crate) fn create_window(
+    settings: &PistonWindowSettings,
+) -> Result<GlutinWindow, CreationFailure> {
+    // Only panics on this thread are silenced, others still reach the hook
+    let thread = thread::current().id();
+    let previous = Arc::new(panic::take_hook());
+    let hook = Arc::clone(&previous);
+    panic::set_hook(Box::new(move |info| {
+        if thread::current().id() != thread {
+            hook(info);
+        }
+    }));
+    let result = panic::catch_unwind(AssertUnwindSafe(|| GlutinWindow::new(settings)));
+    drop(panic::take_hook());
+    match Arc::try_unwrap(previous) {
+        Ok(previous) => panic::set_hook(previous),
+        Err(previous) => panic::set_hook(Box::new(move |info| previous(info))),
+    }
+
+    match result {
+        Ok(Ok(window)) => Ok(window),
+        Ok(Err(error)) => Err(CreationFailure {
+            message: error.to_string(),
+            context: is_context_error(&*error),
+        }),
+        Err(panic) => {
+            let message = match panic.downcast_ref::<&str>() {
+                Some(message) => message.to_string(),
+                None => match panic.downcast_ref::<String>() {
+                    Some(message) => message.clone(),
+                    None => "the window could not be created".to_string(),
+                },
+            };
+            Err(CreationFailure {
+                message,
+                context: false,
+            })
+        }
+    }
+}
+
+/// Create the backend for a window, which is headless if the settings ask
+/// for it
+fn create_backend(settings: &WindowSettings) -> PyResult<Backend> {
+    if settings.headless {
+        Ok(Backend::headless(&settings._piston))
+    } else {
+        Ok(create_window(&settings._piston)?.into())
+    }
+}
+
 #[pyclass(module = "piston2d.window")]
 #[derive(Clone)]
 pub struct WindowSettings {
     pub _piston: PistonWindowSettings,
+    pub headless: bool,
 }
 
 #[pymethods]
 impl WindowSettings {
+    /// WindowSettings(title: str, size: Tuple[int, int], headless: bool = False) -> WindowSettings
+    ///
+    /// :param title: The title of the window
+    /// :type title: str
+    /// :param size: The size of the window
+    /// :type size: Tuple[int, int]
+    /// :param headless: Whether windows built with these settings are
+    ///     headless
+    /// :type headless: bool
     #[new]
-    fn new(title: String, size: [u32; 2]) -> Self {
+    #[args(title, size, headless = "false")]
+    fn new(title: String, size: [u32; 2], headless: bool) -> Self {
         WindowSettings {
             _piston: PistonWindowSettings::new(title, size),
+            headless,
         }
     }
 
+    /// Whether built windows are headless. Headless windows need no display
+    /// and never receive input, but still run an event loop with render and
+    /// update events, and cannot be drawn to with OpenGL.
+    ///
+    /// :type: bool
+    #[getter]
+    fn get_headless(&self) -> PyResult<bool> {
+        Ok(self.headless)
+    }
+
+    #[setter]
+    fn set_headless(&mut self, value: bool) -> PyResult<()> {
+        self.headless = value;
+        Ok(())
+    }
+
     /// The title of the window
     ///
     /// :type: str
@@ -197,25 +322,25 @@ impl WindowSettings {
     }
 }
 
-/// Window class, storing information about the window and events
+/// Window class, storing information about the window and events.
+///
+/// Copies of a window are handles to the same window, use :meth:`duplicate`
+/// to open another.
 #[pyclass(unsendable, module = "piston2d.window")]
+#[derive(Clone)]
 pub struct Window {
-    pub _piston: GlutinWindow,
+    pub _piston: Backend,
 
-    // To aid cloning
-    pub _settings: WindowSettings,
+    // The settings to duplicate the window with, kept in sync with the window
+    pub _settings: Rc<RefCell<WindowSettings>>,
 }
 
-impl Clone for Window {
-    fn clone(&self) -> Self {
-        Window {
-            _piston: GlutinWindow::new(&self._settings._piston).unwrap(),
-            _settings: self._settings.clone(),
-        }
-    }
-
-    fn clone_from(&mut self, source: &Self) {
-        *self = source.clone()
+impl Window {
+    fn from_settings(settings: WindowSettings) -> PyResult<Self> {
+        Ok(Window {
+            _piston: create_backend(&settings)?,
+            _settings: Rc::new(RefCell::new(settings)),
+        })
     }
 }
 
@@ -225,13 +350,64 @@ impl Window {
     #[new]
     fn new(settings: Py<WindowSettings>) -> PyResult<Self> {
         // Extract value with the GIL to use it to construct a new window
-        Ok(Python::with_gil(|py| {
-            let window_settings: WindowSettings = settings.extract(py).unwrap();
-            Window {
-                _piston: GlutinWindow::new(&window_settings._piston).unwrap(),
-                _settings: window_settings,
-            }
-        }))
+        Python::with_gil(|py| {
+            let window_settings: WindowSettings = settings.extract(py)?;
+            Window::from_settings(window_settings)
+        })
+    }
+
+    /// The settings the window was created with, updated with changes made
+    /// to it since, such as to its title. A copy is returned. The position is
+    /// not part of the settings, see :attr:`position`.
+    ///
+    /// :type: WindowSettings
+    #[getter]
+    fn settings(&self) -> PyResult<WindowSettings> {
+        Ok(self._settings.borrow().clone())
+    }
+
+    /// duplicate() -> Window
+    ///
+    /// Open another window with the same settings as this one, including any
+    /// changes made to it since it was created, and at the last position set
+    /// on it
+    ///
+    /// :raises WindowCreationError: If the window could not be created
+    ///
+    /// :rtype: Window
+    fn duplicate(&self) -> PyResult<Window> {
+        let mut window = Window::from_settings(self._settings.borrow().clone())?;
+        if let Some(position) = self._piston.last_position() {
+            window._piston.set_position(position);
+        }
+
+        Ok(window)
+    }
+
+    /// __copy__() -> Window
+    ///
+    /// Another handle to the same window, rather than a new window
+    ///
+    /// :rtype: Window
+    fn __copy__(&self) -> PyResult<Window> {
+        Ok(self.clone())
+    }
+
+    /// An id for the window, unique among the windows created by the program.
+    /// Copies of a window have the same id, duplicates do not.
+    ///
+    /// :type: int
+    #[getter]
+    fn id(&self) -> PyResult<u64> {
+        Ok(self._piston.id())
+    }
+
+    /// Whether the window is headless
+    ///
+    /// :type: bool
+    #[getter]
+    fn headless(&self) -> PyResult<bool> {
+        Ok(self._piston.is_headless())
     }
 
     /// The title of the window
@@ -244,7 +420,8 @@ impl Window {
 
     #[setter]
     fn set_title(&mut self, title: String) -> PyResult<()> {
-        self._piston.set_title(title);
+        self._piston.set_title(title.clone());
+        self._settings.borrow_mut()._piston.set_title(title);
         Ok(())
     }
 
@@ -259,6 +436,10 @@ impl Window {
     #[setter]
     fn set_size(&mut self, value: [u32; 2]) -> PyResult<()> {
         self._piston.set_size(Size::from(value));
+        self._settings
+            .borrow_mut()
+            ._piston
+            .set_size(Size::from(value));
         Ok(())
     }
 
@@ -273,6 +454,7 @@ impl Window {
     #[setter]
     fn set_exit_on_esc(&mut self, value: bool) -> PyResult<()> {
         self._piston.set_exit_on_esc(value);
+        self._settings.borrow_mut()._piston.set_exit_on_esc(value);
         Ok(())
     }
 
@@ -288,10 +470,15 @@ impl Window {
     #[setter]
     fn set_automatic_close(&mut self, value: bool) -> PyResult<()> {
         self._piston.set_automatic_close(value);
+        self._settings
+            .borrow_mut()
+            ._piston
+            .set_automatic_close(value);
         Ok(())
     }
 
-    /// The position of the window
+    /// The position of the window. Headless windows have the last position
+    /// set on them, if any.
     ///
     /// :type: Optional[Tuple[int, int]]
     #[getter]
@@ -348,14 +535,15 @@ impl Window {
 impl Window {
     /// is_current() -> bool
     ///
-    /// Gets whether if this window's gl context is the current gl context
+    /// Gets whether if this window's gl context is the current gl context.
+    /// Headless windows have no gl context.
     ///
     /// :rtype: bool
     fn is_current(&self) -> PyResult<bool> {
         Ok(self._piston.is_current())
     }
 
-    /// Make the window's gl context the current gl context
+    /// Make the window's gl context the current gl context, if it has one
     fn make_current(&mut self) -> PyResult<()> {
         self._piston.make_current();
 
@@ -402,11 +590,19 @@ impl Window {
     ///     It is advised to use `window.event.Events` instead of manually
     ///     calling this function.
     ///
+    /// :raises ValueError: If the window is headless and has no pushed
+    ///     events, as it would wait forever
+    ///
     /// :rtype: Event
-    fn wait_event(&mut self) -> PyResult<Event> {
-        Ok(Event {
-            _piston: self._piston.wait_event(),
-        })
+    fn wait_event(&mut self, py: Python) -> PyResult<PyObject> {
+        if self._piston.is_headless() && !self._piston.has_pushed_events() {
+            return Err(PyValueError::new_err(
+                "headless windows never receive events to wait for",
+            ));
+        }
+        let event = self._piston.wait_event();
+        let window_id = self._piston.source_id(&event);
+        new_event(py, event, window_id)
     }
 
     /// wait_event_timeout(seconds) -> Optional[Event]
@@ -421,19 +617,37 @@ impl Window {
     ///
     /// :param seconds: Seconds to wait
     ///
-    /// :type float: int
+    /// :type seconds: float
+    ///
+    /// :raises ValueError: If ``seconds`` is negative or not a finite number
     ///
     /// :rtype: Optional[Event]
-    fn wait_event_timeout(&mut self, seconds: f64) -> PyResult<Option<Event>> {
-        Ok(
-            match self
-                ._piston
-                .wait_event_timeout(Duration::from_secs_f64(seconds))
-            {
-                Some(event) => Some(Event { _piston: event }),
-                _ => None,
-            },
-        )
+    fn wait_event_timeout(&mut self, py: Python, seconds: f64) -> PyResult<Option<PyObject>> {
+        let timeout = Duration::try_from_secs_f64(seconds).map_err(|_| {
+            PyValueError::new_err(format!("invalid timeout of {:?} seconds", seconds))
+        })?;
+        self._piston
+            .wait_event_timeout(timeout)
+            .map(|event| {
+                let window_id = self._piston.source_id(&event);
+                new_event(py, event, window_id)
+            })
+            .transpose()
+    }
+
+    /// push_event(event)
+    ///
+    /// Queue an event, such as one from :meth:`Event.key_press`, to be
+    /// received before any events from the window itself. Pushed events are
+    /// received by headless windows too.
+    ///
+    /// :param event: The event to push
+    ///
+    /// :type event: Event
+    fn push_event(&mut self, event: PyRef<Event>) -> PyResult<()> {
+        self._piston.push_event(event._piston.clone());
+
+        Ok(())
     }
 }
 
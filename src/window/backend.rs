@@ -0,0 +1,324 @@
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    rc::Rc,
+    sync::atomic::{AtomicU64, AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use glutin_window::GlutinWindow;
+use piston::{
+    AdvancedWindow, Event, NoWindow, OpenGLWindow, Position, Size, Window as PistonWindow,
+    WindowSettings as PistonWindowSettings,
+};
+
+/// A real window, or a headless one that never receives input and needs no
+/// display
+#[allow(clippy::large_enum_variant)]
+enum Inner {
+    Glutin(GlutinWindow),
+    Headless(NoWindow),
+}
+
+/// The id of the next window created
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// The number of real windows open
+static OPEN_WINDOWS: AtomicUsize = AtomicUsize::new(0);
+
+/// Counts a real window as open for as long as it is kept.
+///
+/// Each real window has its own event loop, but on X11 the loops share one
+/// connection to the display, and glutin does not keep which window an input
+/// event was for. Input is only known to be from the window it was received
+/// by while no other real window is open.
+pub struct OpenWindow(());
+
+impl OpenWindow {
+    pub fn open() -> Self {
+        OPEN_WINDOWS.fetch_add(1, Ordering::Relaxed);
+        OpenWindow(())
+    }
+}
+
+impl Drop for OpenWindow {
+    fn drop(&mut self) {
+        OPEN_WINDOWS.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+struct State {
+    id: u64,
+    inner: Inner,
+    pushed: VecDeque<Event>,
+    // Whether the last event received was pushed rather than from the window
+    received_pushed: bool,
+    // Set for real windows
+    open: Option<OpenWindow>,
+    // The last position set, which headless windows report as their position
+    position: Option<Position>,
+}
+
+/// The window a :class:`Window` wraps, along with the events pushed to it,
+/// which are received before any from the window itself.
+///
+/// Clones are handles to the same window.
+#[derive(Clone)]
+pub struct Backend {
+    state: Rc<RefCell<State>>,
+}
+
+impl Backend {
+    pub fn headless(settings: &PistonWindowSettings) -> Self {
+        Inner::Headless(NoWindow::new(settings)).into()
+    }
+
+    /// An id for the window, unique among windows created by this process
+    pub fn id(&self) -> u64 {
+        self.state.borrow().id
+    }
+
+    pub fn is_headless(&self) -> bool {
+        matches!(self.state.borrow().inner, Inner::Headless(_))
+    }
+
+    /// Queue an event to be received before any others
+    pub fn push_event(&mut self, event: Event) {
+        self.state.borrow_mut().pushed.push_back(event);
+    }
+
+    /// The last position set on the window, if any
+    pub fn last_position(&self) -> Option<Position> {
+        self.state.borrow().position
+    }
+
+    /// The id of the window the last event received, `event`, came from, or
+    /// `None` for input from a real window while others are open, as it may
+    /// have been for any of them (see `OpenWindow`)
+    pub fn source_id(&self, event: &Event) -> Option<u64> {
+        let state = self.state.borrow();
+        let known = !matches!(event, Event::Input(..))
+            || state.received_pushed
+            || state.open.is_none()
+            || OPEN_WINDOWS.load(Ordering::Relaxed) == 1;
+
+        if known {
+            Some(state.id)
+        } else {
+            None
+        }
+    }
+
+    /// The next pushed event, if any, noting whether the event received
+    /// next is one
+    fn pop_pushed(&mut self) -> Option<Event> {
+        let mut state = self.state.borrow_mut();
+        let event = state.pushed.pop_front();
+        state.received_pushed = event.is_some();
+        event
+    }
+
+    /// Whether any pushed events have not been received yet
+    pub fn has_pushed_events(&self) -> bool {
+        !self.state.borrow().pushed.is_empty()
+    }
+
+    /// Whether the window's gl context is the current one. Headless windows
+    /// have no gl context.
+    pub fn is_current(&self) -> bool {
+        match &self.state.borrow().inner {
+            Inner::Glutin(window) => window.is_current(),
+            Inner::Headless(_) => false,
+        }
+    }
+
+    /// Make the window's gl context the current one, if it has one
+    pub fn make_current(&mut self) {
+        if let Inner::Glutin(window) = &mut self.state.borrow_mut().inner {
+            window.make_current();
+        }
+    }
+}
+
+impl From<Inner> for Backend {
+    fn from(inner: Inner) -> Self {
+        let open = match inner {
+            Inner::Glutin(_) => Some(OpenWindow::open()),
+            Inner::Headless(_) => None,
+        };
+
+        Backend {
+            state: Rc::new(RefCell::new(State {
+                id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+                inner,
+                pushed: VecDeque::new(),
+                received_pushed: false,
+                open,
+                position: None,
+            })),
+        }
+    }
+}
+
+impl From<GlutinWindow> for Backend {
+    fn from(window: GlutinWindow) -> Self {
+        Inner::Glutin(window).into()
+    }
+}
+
+impl PistonWindow for Backend {
+    fn set_should_close(&mut self, value: bool) {
+        match &mut self.state.borrow_mut().inner {
+            Inner::Glutin(window) => window.set_should_close(value),
+            Inner::Headless(window) => window.set_should_close(value),
+        }
+    }
+
+    fn should_close(&self) -> bool {
+        match &self.state.borrow().inner {
+            Inner::Glutin(window) => window.should_close(),
+            Inner::Headless(window) => window.should_close(),
+        }
+    }
+
+    fn size(&self) -> Size {
+        match &self.state.borrow().inner {
+            Inner::Glutin(window) => window.size(),
+            Inner::Headless(window) => window.size(),
+        }
+    }
+
+    fn swap_buffers(&mut self) {
+        match &mut self.state.borrow_mut().inner {
+            Inner::Glutin(window) => window.swap_buffers(),
+            Inner::Headless(window) => window.swap_buffers(),
+        }
+    }
+
+    fn wait_event(&mut self) -> Event {
+        if let Some(event) = self.pop_pushed() {
+            return event;
+        }
+        match &mut self.state.borrow_mut().inner {
+            Inner::Glutin(window) => window.wait_event(),
+            Inner::Headless(window) => window.wait_event(),
+        }
+    }
+
+    fn wait_event_timeout(&mut self, timeout: Duration) -> Option<Event> {
+        if let Some(event) = self.pop_pushed() {
+            return Some(event);
+        }
+        match &mut self.state.borrow_mut().inner {
+            Inner::Glutin(window) => window.wait_event_timeout(timeout),
+            Inner::Headless(window) => window.wait_event_timeout(timeout),
+        }
+    }
+
+    fn poll_event(&mut self) -> Option<Event> {
+        if let Some(event) = self.pop_pushed() {
+            return Some(event);
+        }
+        match &mut self.state.borrow_mut().inner {
+            Inner::Glutin(window) => window.poll_event(),
+            Inner::Headless(window) => window.poll_event(),
+        }
+    }
+
+    fn draw_size(&self) -> Size {
+        match &self.state.borrow().inner {
+            Inner::Glutin(window) => window.draw_size(),
+            Inner::Headless(window) => window.draw_size(),
+        }
+    }
+}
+
+impl AdvancedWindow for Backend {
+    fn get_title(&self) -> String {
+        match &self.state.borrow().inner {
+            Inner::Glutin(window) => window.get_title(),
+            Inner::Headless(window) => window.get_title(),
+        }
+    }
+
+    fn set_title(&mut self, value: String) {
+        match &mut self.state.borrow_mut().inner {
+            Inner::Glutin(window) => window.set_title(value),
+            Inner::Headless(window) => window.set_title(value),
+        }
+    }
+
+    fn get_exit_on_esc(&self) -> bool {
+        match &self.state.borrow().inner {
+            Inner::Glutin(window) => window.get_exit_on_esc(),
+            Inner::Headless(window) => window.get_exit_on_esc(),
+        }
+    }
+
+    fn set_exit_on_esc(&mut self, value: bool) {
+        match &mut self.state.borrow_mut().inner {
+            Inner::Glutin(window) => window.set_exit_on_esc(value),
+            Inner::Headless(window) => window.set_exit_on_esc(value),
+        }
+    }
+
+    fn get_automatic_close(&self) -> bool {
+        match &self.state.borrow().inner {
+            Inner::Glutin(window) => window.get_automatic_close(),
+            Inner::Headless(window) => window.get_automatic_close(),
+        }
+    }
+
+    fn set_automatic_close(&mut self, value: bool) {
+        match &mut self.state.borrow_mut().inner {
+            Inner::Glutin(window) => window.set_automatic_close(value),
+            Inner::Headless(window) => window.set_automatic_close(value),
+        }
+    }
+
+    fn set_capture_cursor(&mut self, value: bool) {
+        match &mut self.state.borrow_mut().inner {
+            Inner::Glutin(window) => window.set_capture_cursor(value),
+            Inner::Headless(window) => window.set_capture_cursor(value),
+        }
+    }
+
+    fn show(&mut self) {
+        match &mut self.state.borrow_mut().inner {
+            Inner::Glutin(window) => window.show(),
+            Inner::Headless(window) => window.show(),
+        }
+    }
+
+    fn hide(&mut self) {
+        match &mut self.state.borrow_mut().inner {
+            Inner::Glutin(window) => window.hide(),
+            Inner::Headless(window) => window.hide(),
+        }
+    }
+
+    fn get_position(&self) -> Option<Position> {
+        let state = self.state.borrow();
+        match &state.inner {
+            Inner::Glutin(window) => window.get_position(),
+            Inner::Headless(_) => state.position,
+        }
+    }
+
+    fn set_position<P: Into<Position>>(&mut self, val: P) {
+        let position = val.into();
+        let mut state = self.state.borrow_mut();
+        state.position = Some(position);
+        match &mut state.inner {
+            Inner::Glutin(window) => window.set_position(position),
+            Inner::Headless(window) => window.set_position(position),
+        }
+    }
+
+    fn set_size<S: Into<Size>>(&mut self, val: S) {
+        match &mut self.state.borrow_mut().inner {
+            Inner::Glutin(window) => window.set_size(val),
+            Inner::Headless(window) => window.set_size(val),
+        }
+    }
+}
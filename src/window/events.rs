@@ -1,23 +1,357 @@
+use std::path::PathBuf;
+
 use graphics::Viewport as PistonViewport;
-use pyo3::prelude::*;
+use pyo3::{
+    exceptions::{PyTypeError, PyValueError},
+    prelude::*,
+    PyIterProtocol,
+};
 
 use piston::{
-    Button as PistonButton, Event as PistonEvent, EventSettings as PistonEventSettings,
-    Events as PistonEvents, PressEvent, ReleaseEvent, RenderArgs as PistonRenderArgs, RenderEvent,
-    UpdateArgs as PistonUpdateArgs, UpdateEvent,
+    AfterRenderArgs as PistonAfterRenderArgs, AfterRenderEvent, Button as PistonButton, ButtonArgs,
+    ButtonState, CloseArgs as PistonCloseArgs, CloseEvent,
+    ControllerAxisArgs as PistonControllerAxisArgs, ControllerAxisEvent, CursorEvent,
+    Event as PistonEvent, EventLoop, EventSettings as PistonEventSettings, Events as PistonEvents,
+    FileDrag as PistonFileDrag, FocusEvent, IdleArgs as PistonIdleArgs, IdleEvent,
+    Input as PistonInput, Loop as PistonLoop, Motion as PistonMotion, MouseCursorEvent,
+    MouseRelativeEvent, MouseScrollEvent, PressEvent, ReleaseEvent, RenderArgs as PistonRenderArgs,
+    RenderEvent, ResizeArgs as PistonResizeArgs, ResizeEvent, TextEvent, Touch as PistonTouch,
+    TouchArgs as PistonTouchArgs, TouchEvent, UpdateArgs as PistonUpdateArgs, UpdateEvent,
 };
 
-use crate::input::Button;
+use crate::input::{Button, ButtonValue};
 
 use super::Window;
 
-#[pyclass(module = "piston2d.window.events")]
+mod kinds;
+mod multi;
+mod record;
+
+pub use kinds::new_event;
+use kinds::EventKind;
+
+/// An event from a window. Events are created as the subclass for their
+/// :attr:`kind`, such as :class:`KeyPress`, for use with ``match``.
+///
+/// Events can also be created, such as with :meth:`key_press`, and pushed to
+/// a window with :meth:`Window.push_event`.
+#[pyclass(subclass, module = "piston2d.window.events")]
 pub struct Event {
     pub _piston: PistonEvent,
+    pub window_id: Option<u64>,
+}
+
+/// Create an input event, as if it came from a window
+fn input_event(py: Python, input: PistonInput) -> PyResult<PyObject> {
+    new_event(py, PistonEvent::Input(input, None), None)
+}
+
+/// Create a button event, as if it came from a window
+fn button_event(
+    py: Python,
+    state: ButtonState,
+    button: PistonButton,
+    scancode: Option<i32>,
+) -> PyResult<PyObject> {
+    input_event(
+        py,
+        PistonInput::Button(ButtonArgs {
+            state,
+            button,
+            scancode,
+        }),
+    )
 }
 
 #[pymethods]
 impl Event {
+    /// key_press(key: Union[Key, Button, int], scancode: Optional[int] = None) -> KeyPress
+    ///
+    /// A key press event
+    ///
+    /// :param key: The key pressed
+    /// :type key: Union[Key, Button, int]
+    /// :param scancode: The scancode of the key
+    /// :type scancode: Optional[int]
+    /// :rtype: KeyPress
+    #[staticmethod]
+    #[args(key, scancode = "None")]
+    fn key_press(py: Python, key: ButtonValue, scancode: Option<i32>) -> PyResult<PyObject> {
+        let key = PistonButton::Keyboard(key.key()?);
+        button_event(py, ButtonState::Press, key, scancode)
+    }
+
+    /// key_release(key: Union[Key, Button, int], scancode: Optional[int] = None) -> KeyRelease
+    ///
+    /// A key release event
+    ///
+    /// :param key: The key released
+    /// :type key: Union[Key, Button, int]
+    /// :param scancode: The scancode of the key
+    /// :type scancode: Optional[int]
+    /// :rtype: KeyRelease
+    #[staticmethod]
+    #[args(key, scancode = "None")]
+    fn key_release(py: Python, key: ButtonValue, scancode: Option<i32>) -> PyResult<PyObject> {
+        let key = PistonButton::Keyboard(key.key()?);
+        button_event(py, ButtonState::Release, key, scancode)
+    }
+
+    /// mouse_press(button: Union[MouseButton, Button, int]) -> MousePress
+    ///
+    /// A mouse button press event
+    ///
+    /// :param button: The mouse button pressed
+    /// :type button: Union[MouseButton, Button, int]
+    /// :rtype: MousePress
+    #[staticmethod]
+    #[args(button)]
+    fn mouse_press(py: Python, button: ButtonValue) -> PyResult<PyObject> {
+        let button = PistonButton::Mouse(button.mouse_button()?);
+        button_event(py, ButtonState::Press, button, None)
+    }
+
+    /// mouse_release(button: Union[MouseButton, Button, int]) -> MouseRelease
+    ///
+    /// A mouse button release event
+    ///
+    /// :param button: The mouse button released
+    /// :type button: Union[MouseButton, Button, int]
+    /// :rtype: MouseRelease
+    #[staticmethod]
+    #[args(button)]
+    fn mouse_release(py: Python, button: ButtonValue) -> PyResult<PyObject> {
+        let button = PistonButton::Mouse(button.mouse_button()?);
+        button_event(py, ButtonState::Release, button, None)
+    }
+
+    /// button_press(button: Union[Button, Key, MouseButton, ControllerButton]) -> Event
+    ///
+    /// A press event for any button, such as a controller button
+    ///
+    /// :param button: The button pressed
+    /// :type button: Union[Button, Key, MouseButton, ControllerButton]
+    /// :rtype: Event
+    #[staticmethod]
+    #[args(button)]
+    fn button_press(py: Python, button: ButtonValue) -> PyResult<PyObject> {
+        button_event(py, ButtonState::Press, button.button(), None)
+    }
+
+    /// button_release(button: Union[Button, Key, MouseButton, ControllerButton]) -> Event
+    ///
+    /// A release event for any button, such as a controller button
+    ///
+    /// :param button: The button released
+    /// :type button: Union[Button, Key, MouseButton, ControllerButton]
+    /// :rtype: Event
+    #[staticmethod]
+    #[args(button)]
+    fn button_release(py: Python, button: ButtonValue) -> PyResult<PyObject> {
+        button_event(py, ButtonState::Release, button.button(), None)
+    }
+
+    /// mouse_move(x: float, y: float) -> MouseMove
+    ///
+    /// A mouse cursor move event, in window coordinates
+    ///
+    /// :rtype: MouseMove
+    #[staticmethod]
+    #[args(x, y)]
+    fn mouse_move(py: Python, x: f64, y: f64) -> PyResult<PyObject> {
+        input_event(py, PistonInput::Move(PistonMotion::MouseCursor([x, y])))
+    }
+
+    /// mouse_relative(dx: float, dy: float) -> MouseRelative
+    ///
+    /// A relative mouse move event
+    ///
+    /// :rtype: MouseRelative
+    #[staticmethod]
+    #[args(dx, dy)]
+    fn mouse_relative(py: Python, dx: f64, dy: f64) -> PyResult<PyObject> {
+        input_event(py, PistonInput::Move(PistonMotion::MouseRelative([dx, dy])))
+    }
+
+    /// mouse_scroll(dx: float, dy: float) -> MouseScroll
+    ///
+    /// A mouse scroll event
+    ///
+    /// :rtype: MouseScroll
+    #[staticmethod]
+    #[args(dx, dy)]
+    fn mouse_scroll(py: Python, dx: f64, dy: f64) -> PyResult<PyObject> {
+        input_event(py, PistonInput::Move(PistonMotion::MouseScroll([dx, dy])))
+    }
+
+    /// controller_axis(id: int, axis: int, position: float) -> ControllerAxis
+    ///
+    /// A controller axis event
+    ///
+    /// :param id: The id of the controller
+    /// :type id: int
+    /// :param axis: The axis that moved
+    /// :type axis: int
+    /// :param position: The position of the axis, from -1 to 1
+    /// :type position: float
+    /// :rtype: ControllerAxis
+    #[staticmethod]
+    #[args(id, axis, position)]
+    fn controller_axis(py: Python, id: u32, axis: u8, position: f64) -> PyResult<PyObject> {
+        input_event(
+            py,
+            PistonInput::Move(PistonMotion::ControllerAxis(PistonControllerAxisArgs::new(
+                id, axis, position,
+            ))),
+        )
+    }
+
+    /// text(text: str) -> Text
+    ///
+    /// A text event
+    ///
+    /// :rtype: Text
+    #[staticmethod]
+    #[args(text)]
+    fn text(py: Python, text: String) -> PyResult<PyObject> {
+        input_event(py, PistonInput::Text(text))
+    }
+
+    /// resize(width: float, height: float) -> Resize
+    ///
+    /// A window resize event, with the draw size the same as the window size
+    ///
+    /// :rtype: Resize
+    #[staticmethod]
+    #[args(width, height)]
+    fn resize(py: Python, width: f64, height: f64) -> PyResult<PyObject> {
+        input_event(
+            py,
+            PistonInput::Resize(PistonResizeArgs {
+                window_size: [width, height],
+                draw_size: [width as u32, height as u32],
+            }),
+        )
+    }
+
+    /// focus(focused: bool) -> Focus
+    ///
+    /// A window focus event
+    ///
+    /// :rtype: Focus
+    #[staticmethod]
+    #[args(focused)]
+    fn focus(py: Python, focused: bool) -> PyResult<PyObject> {
+        input_event(py, PistonInput::Focus(focused))
+    }
+
+    /// cursor(inside: bool) -> Cursor
+    ///
+    /// An event for the cursor entering or leaving the window
+    ///
+    /// :rtype: Cursor
+    #[staticmethod]
+    #[args(inside)]
+    fn cursor(py: Python, inside: bool) -> PyResult<PyObject> {
+        input_event(py, PistonInput::Cursor(inside))
+    }
+
+    /// file_drop(path: str) -> FileDrop
+    ///
+    /// An event for a file dropped on the window
+    ///
+    /// :rtype: FileDrop
+    #[staticmethod]
+    #[args(path)]
+    fn file_drop(py: Python, path: String) -> PyResult<PyObject> {
+        let path = PathBuf::from(path);
+        input_event(py, PistonInput::FileDrag(PistonFileDrag::Drop(path)))
+    }
+
+    /// close() -> Close
+    ///
+    /// A window close event
+    ///
+    /// :rtype: Close
+    #[staticmethod]
+    fn close(py: Python) -> PyResult<PyObject> {
+        input_event(py, PistonInput::Close(PistonCloseArgs))
+    }
+
+    /// render(ext_dt: float, width: float, height: float) -> Render
+    ///
+    /// A render event, with the draw size the same as the window size
+    ///
+    /// :rtype: Render
+    #[staticmethod]
+    #[args(ext_dt, width, height)]
+    fn render(py: Python, ext_dt: f64, width: f64, height: f64) -> PyResult<PyObject> {
+        let args = PistonRenderArgs {
+            ext_dt,
+            window_size: [width, height],
+            draw_size: [width as u32, height as u32],
+        };
+        new_event(py, PistonEvent::Loop(PistonLoop::Render(args)), None)
+    }
+
+    /// after_render() -> AfterRender
+    ///
+    /// An event for after rendering
+    ///
+    /// :rtype: AfterRender
+    #[staticmethod]
+    fn after_render(py: Python) -> PyResult<PyObject> {
+        let args = PistonAfterRenderArgs;
+        new_event(py, PistonEvent::Loop(PistonLoop::AfterRender(args)), None)
+    }
+
+    /// update(dt: float) -> Update
+    ///
+    /// An update event
+    ///
+    /// :param dt: Delta time in seconds
+    /// :type dt: float
+    /// :rtype: Update
+    #[staticmethod]
+    #[args(dt)]
+    fn update(py: Python, dt: f64) -> PyResult<PyObject> {
+        let args = PistonUpdateArgs { dt };
+        new_event(py, PistonEvent::Loop(PistonLoop::Update(args)), None)
+    }
+
+    /// idle(dt: float) -> Idle
+    ///
+    /// An idle event
+    ///
+    /// :param dt: Expected idle time in seconds
+    /// :type dt: float
+    /// :rtype: Idle
+    #[staticmethod]
+    #[args(dt)]
+    fn idle(py: Python, dt: f64) -> PyResult<PyObject> {
+        let args = PistonIdleArgs { dt };
+        new_event(py, PistonEvent::Loop(PistonLoop::Idle(args)), None)
+    }
+
+    /// The kind of event
+    ///
+    /// :type: EventKind
+    #[getter]
+    fn kind(&self) -> PyResult<EventKind> {
+        Ok(EventKind::of(&self._piston))
+    }
+
+    /// The :attr:`Window.id` of the window the event was received from, or
+    /// ``None`` for events that were created and not received from a window.
+    /// Input from the display is also ``None`` while more than one real
+    /// window is open, as which of them it was for is not known.
+    ///
+    /// :type: Optional[int]
+    #[getter]
+    fn window_id(&self) -> PyResult<Option<u64>> {
+        Ok(self.window_id)
+    }
+
     /// is_input() -> bool
     ///
     /// Whether the event comes from an ``Event::Input``
@@ -167,6 +501,147 @@ impl Event {
             None => Ok(None),
         }
     }
+
+    /// mouse_cursor_args() -> Optional[MouseCursorArgs]
+    ///
+    /// The new cursor position, if this is a mouse move event
+    ///
+    /// :rtype: Optional[MouseCursorArgs]
+    fn mouse_cursor_args(&self) -> PyResult<Option<MouseCursorArgs>> {
+        Ok(self
+            ._piston
+            .mouse_cursor_args()
+            .map(|position| MouseCursorArgs { position }))
+    }
+
+    /// mouse_relative_args() -> Optional[MouseRelativeArgs]
+    ///
+    /// The relative mouse movement, if this is a relative mouse move event
+    ///
+    /// :rtype: Optional[MouseRelativeArgs]
+    fn mouse_relative_args(&self) -> PyResult<Option<MouseRelativeArgs>> {
+        Ok(self
+            ._piston
+            .mouse_relative_args()
+            .map(|delta| MouseRelativeArgs { delta }))
+    }
+
+    /// mouse_scroll_args() -> Optional[MouseScrollArgs]
+    ///
+    /// The scroll amount, if this is a mouse scroll event
+    ///
+    /// :rtype: Optional[MouseScrollArgs]
+    fn mouse_scroll_args(&self) -> PyResult<Option<MouseScrollArgs>> {
+        Ok(self
+            ._piston
+            .mouse_scroll_args()
+            .map(|delta| MouseScrollArgs { delta }))
+    }
+
+    /// text_args() -> Optional[TextArgs]
+    ///
+    /// The text entered, if this is a text event
+    ///
+    /// :rtype: Optional[TextArgs]
+    fn text_args(&self) -> PyResult<Option<TextArgs>> {
+        Ok(self._piston.text_args().map(|text| TextArgs { text }))
+    }
+
+    /// resize_args() -> Optional[ResizeArgs]
+    ///
+    /// The new size, if this is a window resize event
+    ///
+    /// :rtype: Optional[ResizeArgs]
+    fn resize_args(&self) -> PyResult<Option<ResizeArgs>> {
+        Ok(self._piston.resize_args().map(ResizeArgs::from))
+    }
+
+    /// focus_args() -> Optional[FocusArgs]
+    ///
+    /// Whether the window gained focus, if this is a focus event
+    ///
+    /// :rtype: Optional[FocusArgs]
+    fn focus_args(&self) -> PyResult<Option<FocusArgs>> {
+        Ok(self
+            ._piston
+            .focus_args()
+            .map(|focused| FocusArgs { focused }))
+    }
+
+    /// cursor_args() -> Optional[CursorArgs]
+    ///
+    /// Whether the cursor entered the window, if this is a cursor event
+    ///
+    /// :rtype: Optional[CursorArgs]
+    fn cursor_args(&self) -> PyResult<Option<CursorArgs>> {
+        Ok(self
+            ._piston
+            .cursor_args()
+            .map(|inside| CursorArgs { inside }))
+    }
+
+    /// close_args() -> Optional[CloseArgs]
+    ///
+    /// The close args, if this is a window close event
+    ///
+    /// :rtype: Optional[CloseArgs]
+    fn close_args(&self) -> PyResult<Option<CloseArgs>> {
+        Ok(self._piston.close_args().map(|_| CloseArgs {}))
+    }
+
+    /// idle_args() -> Optional[IdleArgs]
+    ///
+    /// The idle args, if this is an idle event
+    ///
+    /// :rtype: Optional[IdleArgs]
+    fn idle_args(&self) -> PyResult<Option<IdleArgs>> {
+        Ok(self._piston.idle_args().map(IdleArgs::from))
+    }
+
+    /// after_render_args() -> Optional[AfterRenderArgs]
+    ///
+    /// The after render args, if this event comes after rendering and
+    /// swapping buffers
+    ///
+    /// :rtype: Optional[AfterRenderArgs]
+    fn after_render_args(&self) -> PyResult<Option<AfterRenderArgs>> {
+        Ok(self._piston.after_render_args().map(|_| AfterRenderArgs {}))
+    }
+
+    /// controller_axis_args() -> Optional[ControllerAxisArgs]
+    ///
+    /// The controller axis that moved, if this is a controller axis event
+    ///
+    /// :rtype: Optional[ControllerAxisArgs]
+    fn controller_axis_args(&self) -> PyResult<Option<ControllerAxisArgs>> {
+        Ok(self
+            ._piston
+            .controller_axis_args()
+            .map(ControllerAxisArgs::from))
+    }
+
+    /// touch_args() -> Optional[TouchArgs]
+    ///
+    /// The touch, if this is a touch event
+    ///
+    /// :rtype: Optional[TouchArgs]
+    fn touch_args(&self) -> PyResult<Option<TouchArgs>> {
+        Ok(self._piston.touch_args().map(TouchArgs::from))
+    }
+
+    /// file_drag_args() -> Optional[FileDragArgs]
+    ///
+    /// The file being dragged or dropped, if this is a file drag event
+    ///
+    /// :rtype: Optional[FileDragArgs]
+    fn file_drag_args(&self) -> PyResult<Option<FileDragArgs>> {
+        match &self._piston {
+            PistonEvent::Input(PistonInput::FileDrag(drag), _) => Ok(Some(FileDragArgs {
+                _piston: drag.clone(),
+            })),
+            _ => Ok(None),
+        }
+    }
 }
 
 /// Render args for an event
@@ -231,6 +706,321 @@ impl From<PistonUpdateArgs> for UpdateArgs {
     }
 }
 
+/// Mouse cursor args for an event, in window coordinates
+#[pyclass(module = "piston2d.window.events")]
+pub struct MouseCursorArgs {
+    position: [f64; 2],
+}
+
+#[pymethods]
+impl MouseCursorArgs {
+    /// The x position of the cursor
+    #[getter]
+    fn x(&self) -> PyResult<f64> {
+        Ok(self.position[0])
+    }
+
+    /// The y position of the cursor
+    #[getter]
+    fn y(&self) -> PyResult<f64> {
+        Ok(self.position[1])
+    }
+
+    /// The position of the cursor
+    #[getter]
+    fn position(&self) -> PyResult<[f64; 2]> {
+        Ok(self.position)
+    }
+}
+
+/// Relative mouse movement args for an event
+#[pyclass(module = "piston2d.window.events")]
+pub struct MouseRelativeArgs {
+    delta: [f64; 2],
+}
+
+#[pymethods]
+impl MouseRelativeArgs {
+    /// The horizontal movement
+    #[getter]
+    fn dx(&self) -> PyResult<f64> {
+        Ok(self.delta[0])
+    }
+
+    /// The vertical movement
+    #[getter]
+    fn dy(&self) -> PyResult<f64> {
+        Ok(self.delta[1])
+    }
+
+    /// The movement
+    #[getter]
+    fn delta(&self) -> PyResult<[f64; 2]> {
+        Ok(self.delta)
+    }
+}
+
+/// Mouse scroll args for an event
+#[pyclass(module = "piston2d.window.events")]
+pub struct MouseScrollArgs {
+    delta: [f64; 2],
+}
+
+#[pymethods]
+impl MouseScrollArgs {
+    /// The horizontal scroll
+    #[getter]
+    fn dx(&self) -> PyResult<f64> {
+        Ok(self.delta[0])
+    }
+
+    /// The vertical scroll
+    #[getter]
+    fn dy(&self) -> PyResult<f64> {
+        Ok(self.delta[1])
+    }
+
+    /// The scroll
+    #[getter]
+    fn delta(&self) -> PyResult<[f64; 2]> {
+        Ok(self.delta)
+    }
+}
+
+/// Text args for an event
+#[pyclass(module = "piston2d.window.events")]
+pub struct TextArgs {
+    text: String,
+}
+
+#[pymethods]
+impl TextArgs {
+    /// The text entered
+    #[getter]
+    fn text(&self) -> PyResult<String> {
+        Ok(self.text.clone())
+    }
+}
+
+/// Resize args for an event
+#[pyclass(module = "piston2d.window.events")]
+pub struct ResizeArgs {
+    _piston: PistonResizeArgs,
+}
+
+#[pymethods]
+impl ResizeArgs {
+    /// The window size in points
+    #[getter]
+    fn window_size(&self) -> PyResult<[f64; 2]> {
+        Ok(self._piston.window_size)
+    }
+
+    /// The draw size in pixels
+    #[getter]
+    fn draw_size(&self) -> PyResult<[u32; 2]> {
+        Ok(self._piston.draw_size)
+    }
+}
+
+impl From<PistonResizeArgs> for ResizeArgs {
+    fn from(pra: PistonResizeArgs) -> Self {
+        ResizeArgs { _piston: pra }
+    }
+}
+
+/// Focus args for an event
+#[pyclass(module = "piston2d.window.events")]
+pub struct FocusArgs {
+    focused: bool,
+}
+
+#[pymethods]
+impl FocusArgs {
+    /// Whether the window gained focus
+    #[getter]
+    fn focused(&self) -> PyResult<bool> {
+        Ok(self.focused)
+    }
+}
+
+/// Cursor args for an event
+#[pyclass(module = "piston2d.window.events")]
+pub struct CursorArgs {
+    inside: bool,
+}
+
+#[pymethods]
+impl CursorArgs {
+    /// Whether the cursor entered the window
+    #[getter]
+    fn inside(&self) -> PyResult<bool> {
+        Ok(self.inside)
+    }
+}
+
+/// Close args for an event
+#[pyclass(module = "piston2d.window.events")]
+pub struct CloseArgs {}
+
+/// Idle args for an event
+#[pyclass(module = "piston2d.window.events")]
+pub struct IdleArgs {
+    _piston: PistonIdleArgs,
+}
+
+#[pymethods]
+impl IdleArgs {
+    /// Expected idle time in seconds
+    #[getter]
+    fn dt(&self) -> PyResult<f64> {
+        Ok(self._piston.dt)
+    }
+}
+
+impl From<PistonIdleArgs> for IdleArgs {
+    fn from(pia: PistonIdleArgs) -> Self {
+        IdleArgs { _piston: pia }
+    }
+}
+
+/// After render args for an event
+#[pyclass(module = "piston2d.window.events")]
+pub struct AfterRenderArgs {}
+
+/// Controller axis args for an event
+#[pyclass(module = "piston2d.window.events")]
+pub struct ControllerAxisArgs {
+    _piston: PistonControllerAxisArgs,
+}
+
+#[pymethods]
+impl ControllerAxisArgs {
+    /// The id of the controller
+    #[getter]
+    fn id(&self) -> PyResult<u32> {
+        Ok(self._piston.id)
+    }
+
+    /// The axis that moved
+    #[getter]
+    fn axis(&self) -> PyResult<u8> {
+        Ok(self._piston.axis)
+    }
+
+    /// The position of the axis, from -1 to 1
+    #[getter]
+    fn position(&self) -> PyResult<f64> {
+        Ok(self._piston.position)
+    }
+}
+
+impl From<PistonControllerAxisArgs> for ControllerAxisArgs {
+    fn from(pcaa: PistonControllerAxisArgs) -> Self {
+        ControllerAxisArgs { _piston: pcaa }
+    }
+}
+
+/// Touch args for an event. Positions and pressures are normalized to 0..1.
+#[pyclass(module = "piston2d.window.events")]
+pub struct TouchArgs {
+    _piston: PistonTouchArgs,
+}
+
+#[pymethods]
+impl TouchArgs {
+    /// The id of the touch device
+    #[getter]
+    fn device(&self) -> PyResult<i64> {
+        Ok(self._piston.device)
+    }
+
+    /// The id of the touch, which may be reused once the touch ends
+    #[getter]
+    fn id(&self) -> PyResult<i64> {
+        Ok(self._piston.id)
+    }
+
+    /// The position of the touch in 2D
+    #[getter]
+    fn position(&self) -> PyResult<[f64; 2]> {
+        Ok(self._piston.position())
+    }
+
+    /// The position of the touch in 3D
+    #[getter]
+    fn position_3d(&self) -> PyResult<[f64; 3]> {
+        Ok(self._piston.position_3d)
+    }
+
+    /// The pressure of the touch
+    #[getter]
+    fn pressure(&self) -> PyResult<f64> {
+        Ok(self._piston.pressure())
+    }
+
+    /// The pressure of the touch as a 3D vector
+    #[getter]
+    fn pressure_3d(&self) -> PyResult<[f64; 3]> {
+        Ok(self._piston.pressure_3d)
+    }
+
+    /// Whether the touch is in 3D
+    #[getter]
+    fn is_3d(&self) -> PyResult<bool> {
+        Ok(self._piston.is_3d)
+    }
+
+    /// The state of the touch, one of ``"start"``, ``"move"``, ``"end"`` or
+    /// ``"cancel"``
+    #[getter]
+    fn touch(&self) -> PyResult<&'static str> {
+        Ok(match self._piston.touch {
+            PistonTouch::Start => "start",
+            PistonTouch::Move => "move",
+            PistonTouch::End => "end",
+            PistonTouch::Cancel => "cancel",
+        })
+    }
+}
+
+impl From<PistonTouchArgs> for TouchArgs {
+    fn from(pta: PistonTouchArgs) -> Self {
+        TouchArgs { _piston: pta }
+    }
+}
+
+/// File drag args for an event
+#[pyclass(module = "piston2d.window.events")]
+pub struct FileDragArgs {
+    _piston: PistonFileDrag,
+}
+
+#[pymethods]
+impl FileDragArgs {
+    /// What happened to the file, one of ``"hover"``, ``"drop"`` or
+    /// ``"cancel"``
+    #[getter]
+    fn kind(&self) -> PyResult<&'static str> {
+        Ok(match self._piston {
+            PistonFileDrag::Hover(_) => "hover",
+            PistonFileDrag::Drop(_) => "drop",
+            PistonFileDrag::Cancel => "cancel",
+        })
+    }
+
+    /// The path of the file, or ``None`` when the drag was cancelled
+    #[getter]
+    fn path(&self) -> PyResult<Option<String>> {
+        Ok(match &self._piston {
+            PistonFileDrag::Hover(path) | PistonFileDrag::Drop(path) => {
+                Some(path.to_string_lossy().into_owned())
+            }
+            PistonFileDrag::Cancel => None,
+        })
+    }
+}
+
 /// Information of the viewport
 #[pyclass(module = "piston2d.window.events")]
 #[derive(Clone, Copy)]
@@ -263,75 +1053,195 @@ impl Viewport {
 #[pyclass(module = "piston2d.window.events")]
 #[derive(Clone, Copy)]
 pub struct EventSettings {
-    _piston: PistonEventSettings,
+    pub _piston: PistonEventSettings,
+}
+
+/// Raise ValueError for a max FPS of 0, which piston would divide by
+fn check_max_fps(max_fps: u64) -> PyResult<u64> {
+    if max_fps == 0 {
+        Err(PyValueError::new_err("max_fps must be at least 1"))
+    } else {
+        Ok(max_fps)
+    }
 }
 
 #[pymethods]
 impl EventSettings {
-    /// EventSettings() -> EventSettings
+    /// EventSettings(max_fps: Optional[int] = None, ups: Optional[int] = None, ups_reset: Optional[int] = None, swap_buffers: Optional[bool] = None, bench_mode: Optional[bool] = None, lazy: Optional[bool] = None) -> EventSettings
     ///
-    /// Create the default settings
+    /// Create the settings, using the defaults for any that are not given
+    ///
+    /// :param max_fps: Max FPS, defaults to 60
+    /// :type max_fps: Optional[int]
+    /// :param ups: Number of updates per second, defaults to 120
+    /// :type ups: Optional[int]
+    /// :param ups_reset: Number of delayed updates before skipping them,
+    ///     defaults to 2
+    /// :type ups_reset: Optional[int]
+    /// :param swap_buffers: Whether to swap buffers automatically, defaults
+    ///     to ``True``
+    /// :type swap_buffers: Optional[bool]
+    /// :param bench_mode: Whether to run in benchmark mode, defaults to
+    ///     ``False``
+    /// :type bench_mode: Optional[bool]
+    /// :param lazy: Whether to only render on input, defaults to ``False``
+    /// :type lazy: Optional[bool]
+    /// :raises ValueError: If ``max_fps`` is 0
     #[new]
-    fn new() -> Self {
-        EventSettings {
-            _piston: PistonEventSettings::new(),
+    #[args(
+        max_fps = "None",
+        ups = "None",
+        ups_reset = "None",
+        swap_buffers = "None",
+        bench_mode = "None",
+        lazy = "None"
+    )]
+    fn new(
+        max_fps: Option<u64>,
+        ups: Option<u64>,
+        ups_reset: Option<u64>,
+        swap_buffers: Option<bool>,
+        bench_mode: Option<bool>,
+        lazy: Option<bool>,
+    ) -> PyResult<Self> {
+        let mut settings = PistonEventSettings::new();
+        if let Some(max_fps) = max_fps {
+            settings.max_fps = check_max_fps(max_fps)?;
+        }
+        if let Some(ups) = ups {
+            settings.ups = ups;
+        }
+        if let Some(ups_reset) = ups_reset {
+            settings.ups_reset = ups_reset;
+        }
+        if let Some(swap_buffers) = swap_buffers {
+            settings.swap_buffers = swap_buffers;
+        }
+        if let Some(bench_mode) = bench_mode {
+            settings.bench_mode = bench_mode;
+        }
+        if let Some(lazy) = lazy {
+            settings.lazy = lazy;
         }
+
+        Ok(EventSettings { _piston: settings })
     }
 
-    /// Max FPS
+    /// Max FPS. Must be at least 1.
+    ///
+    /// :type: int
     #[getter]
     fn max_fps(&self) -> PyResult<u64> {
         Ok(self._piston.max_fps)
     }
 
-    /// Number of updates per second
+    #[setter]
+    fn set_max_fps(&mut self, value: u64) -> PyResult<()> {
+        self._piston.max_fps = check_max_fps(value)?;
+        Ok(())
+    }
+
+    /// Number of updates per second. When 0, update events are disabled.
+    ///
+    /// :type: int
     #[getter]
     fn ups(&self) -> PyResult<u64> {
         Ok(self._piston.ups)
     }
 
-    /// Number of delayed updates to display before skipping them to catch up
+    #[setter]
+    fn set_ups(&mut self, value: u64) -> PyResult<()> {
+        self._piston.ups = value;
+        Ok(())
+    }
+
+    /// Number of delayed updates to display before skipping them to catch up.
+    /// When 0, it always tries to catch up.
+    ///
+    /// :type: int
     #[getter]
     fn ups_reset(&self) -> PyResult<u64> {
         Ok(self._piston.ups_reset)
     }
 
+    #[setter]
+    fn set_ups_reset(&mut self, value: u64) -> PyResult<()> {
+        self._piston.ups_reset = value;
+        Ok(())
+    }
+
     /// Whether display buffers are automatically swapped
+    ///
+    /// :type: bool
     #[getter]
     fn swap_buffers(&self) -> PyResult<bool> {
         Ok(self._piston.swap_buffers)
     }
 
-    /// Whether we are in benchmark mode
+    #[setter]
+    fn set_swap_buffers(&mut self, value: bool) -> PyResult<()> {
+        self._piston.swap_buffers = value;
+        Ok(())
+    }
+
+    /// Whether we are in benchmark mode, rendering and updating without
+    /// sleeping and ignoring input. Requires ``lazy`` to be ``False``.
+    ///
+    /// :type: bool
     #[getter]
     fn bench_mode(&self) -> PyResult<bool> {
         Ok(self._piston.bench_mode)
     }
 
-    /// Whether this event retriever emits update and idle events
+    #[setter]
+    fn set_bench_mode(&mut self, value: bool) -> PyResult<()> {
+        self._piston.bench_mode = value;
+        Ok(())
+    }
+
+    /// Whether this event retriever only renders when receiving input,
+    /// without update events
+    ///
+    /// :type: bool
     #[getter]
     fn lazy(&self) -> PyResult<bool> {
         Ok(self._piston.lazy)
     }
+
+    #[setter]
+    fn set_lazy(&mut self, value: bool) -> PyResult<()> {
+        self._piston.lazy = value;
+        Ok(())
+    }
 }
 
 /// Events utility that provides helper functions for handling the event loop.
+///
+/// Once bound to a window, with ``Events(settings, window)`` or
+/// :meth:`iter`, iterating over it yields events until the loop ends.
 #[pyclass(module = "piston2d.window.events")]
 pub struct Events {
     pub _piston: PistonEvents,
+    window: Option<Py<Window>>,
 }
 
 /// Basic implementation
 #[pymethods]
 impl Events {
-    /// Events(settings: EventSettings) -> Events
+    /// Events(settings: EventSettings, window: Optional[Window] = None) -> Events
     ///
     /// Create a new event loop handler
+    ///
+    /// :param settings: The event loop settings
+    /// :type settings: EventSettings
+    /// :param window: The window to iterate over events of
+    /// :type window: Optional[Window]
     #[new]
-    #[args(settings)]
-    fn new(settings: EventSettings) -> Self {
+    #[args(settings, window = "None")]
+    fn new(settings: EventSettings, window: Option<Py<Window>>) -> Self {
         Events {
             _piston: PistonEvents::new(settings._piston),
+            window,
         }
     }
 
@@ -342,23 +1252,194 @@ impl Events {
     /// :param window: The window to run on
     /// :type window: Window
     /// :returns: Next event or ``None`` (when ending event loop)
+    /// :raises ValueError: If the events are lazy and the window is headless
     /// :rtype: Optional[Event]
     #[args(window)]
-    fn next(&mut self, window: &mut Window) -> PyResult<Option<Event>> {
-        Ok(match self._piston.next(&mut window._piston) {
-            Some(event) => Some(Event { _piston: event }),
-            None => None,
+    fn next(&mut self, py: Python, window: &mut Window) -> PyResult<Option<PyObject>> {
+        next_event(&mut self._piston, window)?
+            .map(|event| {
+                let window_id = window._piston.source_id(&event);
+                new_event(py, event, window_id)
+            })
+            .transpose()
+    }
+
+    /// iter(window: Window) -> Events
+    ///
+    /// Bind the events to ``window``, so iterating over them yields the
+    /// window's events
+    ///
+    /// :param window: The window to run on
+    /// :type window: Window
+    /// :returns: These events
+    /// :rtype: Events
+    #[args(window)]
+    fn iter(mut slf: PyRefMut<Self>, window: Py<Window>) -> PyResult<PyRefMut<Self>> {
+        slf.window = Some(window);
+        Ok(slf)
+    }
+
+    /// The window the events are bound to, if any
+    ///
+    /// :type: Optional[Window]
+    #[getter]
+    fn window(&self, py: Python) -> PyResult<Option<Py<Window>>> {
+        Ok(self.window.as_ref().map(|window| window.clone_ref(py)))
+    }
+
+    /// The settings of the event loop. Setting them restarts the loop's
+    /// timing.
+    ///
+    /// :type: EventSettings
+    #[getter]
+    fn get_settings(&self) -> PyResult<EventSettings> {
+        Ok(EventSettings {
+            _piston: self._piston.get_event_settings(),
+        })
+    }
+
+    #[setter]
+    fn set_settings(&mut self, settings: EventSettings) -> PyResult<()> {
+        self._piston.set_event_settings(settings._piston);
+        Ok(())
+    }
+
+    /// set_max_fps(frames: int) -> None
+    ///
+    /// Change the max FPS of the running loop
+    ///
+    /// :param frames: Max frames per second, at least 1
+    /// :type frames: int
+    /// :raises ValueError: If ``frames`` is 0
+    #[args(frames)]
+    fn set_max_fps(&mut self, frames: u64) -> PyResult<()> {
+        self._piston.set_max_fps(check_max_fps(frames)?);
+        Ok(())
+    }
+
+    /// set_ups(frames: int) -> None
+    ///
+    /// Change the number of updates per second of the running loop
+    ///
+    /// :param frames: Updates per second, or 0 to disable update events
+    /// :type frames: int
+    #[args(frames)]
+    fn set_ups(&mut self, frames: u64) -> PyResult<()> {
+        self._piston.set_ups(frames);
+        Ok(())
+    }
+
+    /// set_ups_reset(frames: int) -> None
+    ///
+    /// Change the number of delayed updates before skipping them to catch up
+    ///
+    /// :param frames: Delayed updates, or 0 to always catch up
+    /// :type frames: int
+    #[args(frames)]
+    fn set_ups_reset(&mut self, frames: u64) -> PyResult<()> {
+        self._piston.set_ups_reset(frames);
+        Ok(())
+    }
+
+    /// set_swap_buffers(enable: bool) -> None
+    ///
+    /// Enable or disable automatically swapping buffers
+    ///
+    /// :param enable: Whether to swap buffers
+    /// :type enable: bool
+    #[args(enable)]
+    fn set_swap_buffers(&mut self, enable: bool) -> PyResult<()> {
+        self._piston.set_swap_buffers(enable);
+        Ok(())
+    }
+
+    /// set_bench_mode(enable: bool) -> None
+    ///
+    /// Enable or disable benchmark mode
+    ///
+    /// :param enable: Whether to run in benchmark mode
+    /// :type enable: bool
+    #[args(enable)]
+    fn set_bench_mode(&mut self, enable: bool) -> PyResult<()> {
+        self._piston.set_bench_mode(enable);
+        Ok(())
+    }
+
+    /// set_lazy(enable: bool) -> None
+    ///
+    /// Enable or disable only rendering when receiving input
+    ///
+    /// :param enable: Whether to be lazy
+    /// :type enable: bool
+    #[args(enable)]
+    fn set_lazy(&mut self, enable: bool) -> PyResult<()> {
+        self._piston.set_lazy(enable);
+        Ok(())
+    }
+}
+
+/// The next piston event for the window, before it is wrapped for Python
+fn next_event(events: &mut PistonEvents, window: &mut Window) -> PyResult<Option<PistonEvent>> {
+    let settings = events.get_event_settings();
+    if window._piston.is_headless()
+        && settings.lazy
+        && !settings.bench_mode
+        && !window._piston.has_pushed_events()
+    {
+        return Err(PyValueError::new_err(
+            "lazy events wait for input, which headless windows never receive",
+        ));
+    }
+
+    Ok(events.next(&mut window._piston))
+}
+
+/// The error for iterating over :class:`Events` without a window
+fn unbound_error() -> PyErr {
+    PyTypeError::new_err("Events is not bound to a window, use events.iter(window)")
+}
+
+#[pyproto]
+impl PyIterProtocol for Events {
+    fn __iter__(slf: PyRef<Self>) -> PyResult<PyRef<Self>> {
+        if slf.window.is_none() {
+            return Err(unbound_error());
+        }
+        Ok(slf)
+    }
+
+    fn __next__(mut slf: PyRefMut<Self>) -> PyResult<Option<PyObject>> {
+        let window = slf.window.clone().ok_or_else(unbound_error)?;
+        Python::with_gil(|py| {
+            let mut window = window.try_borrow_mut(py)?;
+            slf.next(py, &mut window)
         })
     }
 }
 
-pub fn init_submodule(_py: Python, m: &PyModule) -> PyResult<()> {
+pub fn init_submodule(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<Event>()?;
     m.add_class::<Events>()?;
     m.add_class::<EventSettings>()?;
     m.add_class::<Viewport>()?;
     m.add_class::<RenderArgs>()?;
     m.add_class::<UpdateArgs>()?;
+    m.add_class::<MouseCursorArgs>()?;
+    m.add_class::<MouseRelativeArgs>()?;
+    m.add_class::<MouseScrollArgs>()?;
+    m.add_class::<TextArgs>()?;
+    m.add_class::<ResizeArgs>()?;
+    m.add_class::<FocusArgs>()?;
+    m.add_class::<CursorArgs>()?;
+    m.add_class::<CloseArgs>()?;
+    m.add_class::<IdleArgs>()?;
+    m.add_class::<AfterRenderArgs>()?;
+    m.add_class::<ControllerAxisArgs>()?;
+    m.add_class::<TouchArgs>()?;
+    m.add_class::<FileDragArgs>()?;
+    kinds::init_submodule(py, m)?;
+    multi::init_submodule(py, m)?;
+    record::init_submodule(py, m)?;
 
     Ok(())
 }
@@ -0,0 +1,907 @@
+use pyo3::{
+    basic::CompareOp,
+    prelude::*,
+    type_object::{PyBorrowFlagLayout, PySizedLayout, PyTypeInfo},
+    PyClass, PyClassInitializer, PyObjectProtocol,
+};
+
+use piston::{
+    Button as PistonButton, ButtonState, ControllerAxisArgs as PistonControllerAxisArgs,
+    Event as PistonEvent, FileDrag as PistonFileDrag, Input as PistonInput, Key as PistonKey,
+    Loop as PistonLoop, Motion as PistonMotion, MouseButton as PistonMouseButton,
+    RenderArgs as PistonRenderArgs, Touch as PistonTouch, TouchArgs as PistonTouchArgs,
+};
+
+use crate::input::{Button, Key, MouseButton};
+
+use super::{Event, Viewport};
+
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    Render,
+    AfterRender,
+    Update,
+    Idle,
+    KeyPress,
+    KeyRelease,
+    MousePress,
+    MouseRelease,
+    ButtonPress,
+    ButtonRelease,
+    MouseMove,
+    MouseRelative,
+    MouseScroll,
+    ControllerAxis,
+    Touch,
+    Text,
+    Resize,
+    Focus,
+    Cursor,
+    FileHover,
+    FileDrop,
+    FileCancel,
+    Close,
+    Custom,
+}
+
+impl Kind {
+    fn name(self) -> &'static str {
+        match self {
+            Kind::Render => "RENDER",
+            Kind::AfterRender => "AFTER_RENDER",
+            Kind::Update => "UPDATE",
+            Kind::Idle => "IDLE",
+            Kind::KeyPress => "KEY_PRESS",
+            Kind::KeyRelease => "KEY_RELEASE",
+            Kind::MousePress => "MOUSE_PRESS",
+            Kind::MouseRelease => "MOUSE_RELEASE",
+            Kind::ButtonPress => "BUTTON_PRESS",
+            Kind::ButtonRelease => "BUTTON_RELEASE",
+            Kind::MouseMove => "MOUSE_MOVE",
+            Kind::MouseRelative => "MOUSE_RELATIVE",
+            Kind::MouseScroll => "MOUSE_SCROLL",
+            Kind::ControllerAxis => "CONTROLLER_AXIS",
+            Kind::Touch => "TOUCH",
+            Kind::Text => "TEXT",
+            Kind::Resize => "RESIZE",
+            Kind::Focus => "FOCUS",
+            Kind::Cursor => "CURSOR",
+            Kind::FileHover => "FILE_HOVER",
+            Kind::FileDrop => "FILE_DROP",
+            Kind::FileCancel => "FILE_CANCEL",
+            Kind::Close => "CLOSE",
+            Kind::Custom => "CUSTOM",
+        }
+    }
+}
+
+/// The kind of an :class:`Event`, such as ``EventKind.KEY_PRESS``
+#[pyclass(module = "piston2d.window.events")]
+#[derive(Clone, Copy)]
+pub struct EventKind {
+    kind: Kind,
+}
+
+impl EventKind {
+    /// The kind of a piston event
+    pub fn of(event: &PistonEvent) -> Self {
+        let kind = match event {
+            PistonEvent::Input(input, _) => match input {
+                PistonInput::Button(args) => match (args.state, args.button) {
+                    (ButtonState::Press, PistonButton::Keyboard(_)) => Kind::KeyPress,
+                    (ButtonState::Release, PistonButton::Keyboard(_)) => Kind::KeyRelease,
+                    (ButtonState::Press, PistonButton::Mouse(_)) => Kind::MousePress,
+                    (ButtonState::Release, PistonButton::Mouse(_)) => Kind::MouseRelease,
+                    (ButtonState::Press, _) => Kind::ButtonPress,
+                    (ButtonState::Release, _) => Kind::ButtonRelease,
+                },
+                PistonInput::Move(motion) => match motion {
+                    PistonMotion::MouseCursor(_) => Kind::MouseMove,
+                    PistonMotion::MouseRelative(_) => Kind::MouseRelative,
+                    PistonMotion::MouseScroll(_) => Kind::MouseScroll,
+                    PistonMotion::ControllerAxis(_) => Kind::ControllerAxis,
+                    PistonMotion::Touch(_) => Kind::Touch,
+                },
+                PistonInput::Text(_) => Kind::Text,
+                PistonInput::Resize(_) => Kind::Resize,
+                PistonInput::Focus(_) => Kind::Focus,
+                PistonInput::Cursor(_) => Kind::Cursor,
+                PistonInput::FileDrag(PistonFileDrag::Hover(_)) => Kind::FileHover,
+                PistonInput::FileDrag(PistonFileDrag::Drop(_)) => Kind::FileDrop,
+                PistonInput::FileDrag(PistonFileDrag::Cancel) => Kind::FileCancel,
+                PistonInput::Close(_) => Kind::Close,
+            },
+            PistonEvent::Loop(event) => match event {
+                PistonLoop::Render(_) => Kind::Render,
+                PistonLoop::AfterRender(_) => Kind::AfterRender,
+                PistonLoop::Update(_) => Kind::Update,
+                PistonLoop::Idle(_) => Kind::Idle,
+            },
+            PistonEvent::Custom(..) => Kind::Custom,
+        };
+
+        EventKind { kind }
+    }
+}
+
+#[pymethods]
+impl EventKind {
+    #[classattr]
+    const RENDER: EventKind = EventKind { kind: Kind::Render };
+    #[classattr]
+    const AFTER_RENDER: EventKind = EventKind {
+        kind: Kind::AfterRender,
+    };
+    #[classattr]
+    const UPDATE: EventKind = EventKind { kind: Kind::Update };
+    #[classattr]
+    const IDLE: EventKind = EventKind { kind: Kind::Idle };
+    #[classattr]
+    const KEY_PRESS: EventKind = EventKind {
+        kind: Kind::KeyPress,
+    };
+    #[classattr]
+    const KEY_RELEASE: EventKind = EventKind {
+        kind: Kind::KeyRelease,
+    };
+    #[classattr]
+    const MOUSE_PRESS: EventKind = EventKind {
+        kind: Kind::MousePress,
+    };
+    #[classattr]
+    const MOUSE_RELEASE: EventKind = EventKind {
+        kind: Kind::MouseRelease,
+    };
+    #[classattr]
+    const BUTTON_PRESS: EventKind = EventKind {
+        kind: Kind::ButtonPress,
+    };
+    #[classattr]
+    const BUTTON_RELEASE: EventKind = EventKind {
+        kind: Kind::ButtonRelease,
+    };
+    #[classattr]
+    const MOUSE_MOVE: EventKind = EventKind {
+        kind: Kind::MouseMove,
+    };
+    #[classattr]
+    const MOUSE_RELATIVE: EventKind = EventKind {
+        kind: Kind::MouseRelative,
+    };
+    #[classattr]
+    const MOUSE_SCROLL: EventKind = EventKind {
+        kind: Kind::MouseScroll,
+    };
+    #[classattr]
+    const CONTROLLER_AXIS: EventKind = EventKind {
+        kind: Kind::ControllerAxis,
+    };
+    #[classattr]
+    const TOUCH: EventKind = EventKind { kind: Kind::Touch };
+    #[classattr]
+    const TEXT: EventKind = EventKind { kind: Kind::Text };
+    #[classattr]
+    const RESIZE: EventKind = EventKind { kind: Kind::Resize };
+    #[classattr]
+    const FOCUS: EventKind = EventKind { kind: Kind::Focus };
+    #[classattr]
+    const CURSOR: EventKind = EventKind { kind: Kind::Cursor };
+    #[classattr]
+    const FILE_HOVER: EventKind = EventKind {
+        kind: Kind::FileHover,
+    };
+    #[classattr]
+    const FILE_DROP: EventKind = EventKind {
+        kind: Kind::FileDrop,
+    };
+    #[classattr]
+    const FILE_CANCEL: EventKind = EventKind {
+        kind: Kind::FileCancel,
+    };
+    #[classattr]
+    const CLOSE: EventKind = EventKind { kind: Kind::Close };
+    #[classattr]
+    const CUSTOM: EventKind = EventKind { kind: Kind::Custom };
+
+    /// The name of the kind, such as ``"KEY_PRESS"``
+    ///
+    /// :type: str
+    #[getter]
+    fn name(&self) -> PyResult<&'static str> {
+        Ok(self.kind.name())
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for EventKind {
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(format!("EventKind.{}", self.kind.name()))
+    }
+
+    fn __hash__(&self) -> PyResult<u64> {
+        Ok(self.kind as u64)
+    }
+
+    fn __richcmp__(&self, other: PyRef<EventKind>, op: CompareOp) -> PyResult<PyObject> {
+        let py = other.py();
+        Ok(match op {
+            CompareOp::Eq => (self.kind == other.kind).into_py(py),
+            CompareOp::Ne => (self.kind != other.kind).into_py(py),
+            _ => py.NotImplemented(),
+        })
+    }
+}
+
+/// A render event
+#[pyclass(extends = Event, module = "piston2d.window.events")]
+pub struct Render {
+    args: PistonRenderArgs,
+}
+
+#[pymethods]
+impl Render {
+    #[classattr]
+    #[name = "__match_args__"]
+    fn match_args() -> (&'static str, &'static str, &'static str) {
+        ("ext_dt", "window_size", "draw_size")
+    }
+
+    /// The extrapolated delta in seconds
+    #[getter]
+    fn ext_dt(&self) -> PyResult<f64> {
+        Ok(self.args.ext_dt)
+    }
+
+    /// The window size in points
+    #[getter]
+    fn window_size(&self) -> PyResult<[f64; 2]> {
+        Ok(self.args.window_size)
+    }
+
+    /// The draw size in pixels
+    #[getter]
+    fn draw_size(&self) -> PyResult<[u32; 2]> {
+        Ok(self.args.draw_size)
+    }
+
+    /// The viewport
+    #[getter]
+    fn viewport(&self) -> PyResult<Viewport> {
+        Ok(Viewport {
+            _piston: self.args.viewport(),
+        })
+    }
+}
+
+/// An event after rendering and swapping buffers
+#[pyclass(extends = Event, module = "piston2d.window.events")]
+pub struct AfterRender {}
+
+/// An update event
+#[pyclass(extends = Event, module = "piston2d.window.events")]
+pub struct Update {
+    dt: f64,
+}
+
+#[pymethods]
+impl Update {
+    #[classattr]
+    #[name = "__match_args__"]
+    fn match_args() -> (&'static str,) {
+        ("dt",)
+    }
+
+    /// Delta time in seconds
+    #[getter]
+    fn dt(&self) -> PyResult<f64> {
+        Ok(self.dt)
+    }
+}
+
+/// An idle event
+#[pyclass(extends = Event, module = "piston2d.window.events")]
+pub struct Idle {
+    dt: f64,
+}
+
+#[pymethods]
+impl Idle {
+    #[classattr]
+    #[name = "__match_args__"]
+    fn match_args() -> (&'static str,) {
+        ("dt",)
+    }
+
+    /// Expected idle time in seconds
+    #[getter]
+    fn dt(&self) -> PyResult<f64> {
+        Ok(self.dt)
+    }
+}
+
+/// A key was pressed
+#[pyclass(extends = Event, module = "piston2d.window.events")]
+pub struct KeyPress {
+    key: PistonKey,
+    scancode: Option<i32>,
+}
+
+#[pymethods]
+impl KeyPress {
+    #[classattr]
+    #[name = "__match_args__"]
+    fn match_args() -> (&'static str, &'static str) {
+        ("key", "scancode")
+    }
+
+    /// The key pressed
+    #[getter]
+    fn key(&self) -> PyResult<Key> {
+        Ok(self.key.into())
+    }
+
+    /// The scancode of the key, if known
+    #[getter]
+    fn scancode(&self) -> PyResult<Option<i32>> {
+        Ok(self.scancode)
+    }
+}
+
+/// A key was released
+#[pyclass(extends = Event, module = "piston2d.window.events")]
+pub struct KeyRelease {
+    key: PistonKey,
+    scancode: Option<i32>,
+}
+
+#[pymethods]
+impl KeyRelease {
+    #[classattr]
+    #[name = "__match_args__"]
+    fn match_args() -> (&'static str, &'static str) {
+        ("key", "scancode")
+    }
+
+    /// The key released
+    #[getter]
+    fn key(&self) -> PyResult<Key> {
+        Ok(self.key.into())
+    }
+
+    /// The scancode of the key, if known
+    #[getter]
+    fn scancode(&self) -> PyResult<Option<i32>> {
+        Ok(self.scancode)
+    }
+}
+
+/// A mouse button was pressed
+#[pyclass(extends = Event, module = "piston2d.window.events")]
+pub struct MousePress {
+    button: PistonMouseButton,
+}
+
+#[pymethods]
+impl MousePress {
+    #[classattr]
+    #[name = "__match_args__"]
+    fn match_args() -> (&'static str,) {
+        ("button",)
+    }
+
+    /// The mouse button pressed
+    #[getter]
+    fn button(&self) -> PyResult<MouseButton> {
+        Ok(self.button.into())
+    }
+}
+
+/// A mouse button was released
+#[pyclass(extends = Event, module = "piston2d.window.events")]
+pub struct MouseRelease {
+    button: PistonMouseButton,
+}
+
+#[pymethods]
+impl MouseRelease {
+    #[classattr]
+    #[name = "__match_args__"]
+    fn match_args() -> (&'static str,) {
+        ("button",)
+    }
+
+    /// The mouse button released
+    #[getter]
+    fn button(&self) -> PyResult<MouseButton> {
+        Ok(self.button.into())
+    }
+}
+
+/// A controller button or hat was pressed
+#[pyclass(extends = Event, module = "piston2d.window.events")]
+pub struct ButtonPress {
+    button: PistonButton,
+}
+
+#[pymethods]
+impl ButtonPress {
+    #[classattr]
+    #[name = "__match_args__"]
+    fn match_args() -> (&'static str,) {
+        ("button",)
+    }
+
+    /// The button pressed
+    #[getter]
+    fn button(&self) -> PyResult<Button> {
+        Ok(self.button.into())
+    }
+}
+
+/// A controller button or hat was released
+#[pyclass(extends = Event, module = "piston2d.window.events")]
+pub struct ButtonRelease {
+    button: PistonButton,
+}
+
+#[pymethods]
+impl ButtonRelease {
+    #[classattr]
+    #[name = "__match_args__"]
+    fn match_args() -> (&'static str,) {
+        ("button",)
+    }
+
+    /// The button released
+    #[getter]
+    fn button(&self) -> PyResult<Button> {
+        Ok(self.button.into())
+    }
+}
+
+/// The mouse cursor moved, in window coordinates
+#[pyclass(extends = Event, module = "piston2d.window.events")]
+pub struct MouseMove {
+    position: [f64; 2],
+}
+
+#[pymethods]
+impl MouseMove {
+    #[classattr]
+    #[name = "__match_args__"]
+    fn match_args() -> (&'static str, &'static str) {
+        ("x", "y")
+    }
+
+    /// The x position of the cursor
+    #[getter]
+    fn x(&self) -> PyResult<f64> {
+        Ok(self.position[0])
+    }
+
+    /// The y position of the cursor
+    #[getter]
+    fn y(&self) -> PyResult<f64> {
+        Ok(self.position[1])
+    }
+
+    /// The position of the cursor
+    #[getter]
+    fn position(&self) -> PyResult<[f64; 2]> {
+        Ok(self.position)
+    }
+}
+
+/// The mouse moved, relative to its last position
+#[pyclass(extends = Event, module = "piston2d.window.events")]
+pub struct MouseRelative {
+    delta: [f64; 2],
+}
+
+#[pymethods]
+impl MouseRelative {
+    #[classattr]
+    #[name = "__match_args__"]
+    fn match_args() -> (&'static str, &'static str) {
+        ("dx", "dy")
+    }
+
+    /// The horizontal movement
+    #[getter]
+    fn dx(&self) -> PyResult<f64> {
+        Ok(self.delta[0])
+    }
+
+    /// The vertical movement
+    #[getter]
+    fn dy(&self) -> PyResult<f64> {
+        Ok(self.delta[1])
+    }
+}
+
+/// The mouse wheel scrolled
+#[pyclass(extends = Event, module = "piston2d.window.events")]
+pub struct MouseScroll {
+    delta: [f64; 2],
+}
+
+#[pymethods]
+impl MouseScroll {
+    #[classattr]
+    #[name = "__match_args__"]
+    fn match_args() -> (&'static str, &'static str) {
+        ("dx", "dy")
+    }
+
+    /// The horizontal scroll
+    #[getter]
+    fn dx(&self) -> PyResult<f64> {
+        Ok(self.delta[0])
+    }
+
+    /// The vertical scroll
+    #[getter]
+    fn dy(&self) -> PyResult<f64> {
+        Ok(self.delta[1])
+    }
+}
+
+/// A controller axis moved
+#[pyclass(extends = Event, module = "piston2d.window.events")]
+pub struct ControllerAxis {
+    args: PistonControllerAxisArgs,
+}
+
+#[pymethods]
+impl ControllerAxis {
+    #[classattr]
+    #[name = "__match_args__"]
+    fn match_args() -> (&'static str, &'static str, &'static str) {
+        ("id", "axis", "position")
+    }
+
+    /// The id of the controller
+    #[getter]
+    fn id(&self) -> PyResult<u32> {
+        Ok(self.args.id)
+    }
+
+    /// The axis that moved
+    #[getter]
+    fn axis(&self) -> PyResult<u8> {
+        Ok(self.args.axis)
+    }
+
+    /// The position of the axis, from -1 to 1
+    #[getter]
+    fn position(&self) -> PyResult<f64> {
+        Ok(self.args.position)
+    }
+}
+
+/// A touch started, moved, ended or was cancelled
+#[pyclass(extends = Event, module = "piston2d.window.events")]
+pub struct Touch {
+    args: PistonTouchArgs,
+}
+
+#[pymethods]
+impl Touch {
+    #[classattr]
+    #[name = "__match_args__"]
+    fn match_args() -> (&'static str, &'static str, &'static str) {
+        ("touch", "position", "pressure")
+    }
+
+    /// The state of the touch, one of ``"start"``, ``"move"``, ``"end"`` or
+    /// ``"cancel"``
+    #[getter]
+    fn touch(&self) -> PyResult<&'static str> {
+        Ok(match self.args.touch {
+            PistonTouch::Start => "start",
+            PistonTouch::Move => "move",
+            PistonTouch::End => "end",
+            PistonTouch::Cancel => "cancel",
+        })
+    }
+
+    /// The position of the touch in 2D, normalized to 0..1
+    #[getter]
+    fn position(&self) -> PyResult<[f64; 2]> {
+        Ok(self.args.position())
+    }
+
+    /// The pressure of the touch, normalized to 0..1
+    #[getter]
+    fn pressure(&self) -> PyResult<f64> {
+        Ok(self.args.pressure())
+    }
+
+    /// The id of the touch, which may be reused once the touch ends
+    #[getter]
+    fn id(&self) -> PyResult<i64> {
+        Ok(self.args.id)
+    }
+
+    /// The id of the touch device
+    #[getter]
+    fn device(&self) -> PyResult<i64> {
+        Ok(self.args.device)
+    }
+}
+
+/// Text was entered
+#[pyclass(extends = Event, module = "piston2d.window.events")]
+pub struct Text {
+    text: String,
+}
+
+#[pymethods]
+impl Text {
+    #[classattr]
+    #[name = "__match_args__"]
+    fn match_args() -> (&'static str,) {
+        ("text",)
+    }
+
+    /// The text entered
+    #[getter]
+    fn text(&self) -> PyResult<String> {
+        Ok(self.text.clone())
+    }
+}
+
+/// The window was resized
+#[pyclass(extends = Event, module = "piston2d.window.events")]
+pub struct Resize {
+    window_size: [f64; 2],
+    draw_size: [u32; 2],
+}
+
+#[pymethods]
+impl Resize {
+    #[classattr]
+    #[name = "__match_args__"]
+    fn match_args() -> (&'static str, &'static str) {
+        ("window_size", "draw_size")
+    }
+
+    /// The window size in points
+    #[getter]
+    fn window_size(&self) -> PyResult<[f64; 2]> {
+        Ok(self.window_size)
+    }
+
+    /// The draw size in pixels
+    #[getter]
+    fn draw_size(&self) -> PyResult<[u32; 2]> {
+        Ok(self.draw_size)
+    }
+}
+
+/// The window gained or lost focus
+#[pyclass(extends = Event, module = "piston2d.window.events")]
+pub struct Focus {
+    focused: bool,
+}
+
+#[pymethods]
+impl Focus {
+    #[classattr]
+    #[name = "__match_args__"]
+    fn match_args() -> (&'static str,) {
+        ("focused",)
+    }
+
+    /// Whether the window gained focus
+    #[getter]
+    fn focused(&self) -> PyResult<bool> {
+        Ok(self.focused)
+    }
+}
+
+/// The cursor entered or left the window
+#[pyclass(extends = Event, module = "piston2d.window.events")]
+pub struct Cursor {
+    inside: bool,
+}
+
+#[pymethods]
+impl Cursor {
+    #[classattr]
+    #[name = "__match_args__"]
+    fn match_args() -> (&'static str,) {
+        ("inside",)
+    }
+
+    /// Whether the cursor entered the window
+    #[getter]
+    fn inside(&self) -> PyResult<bool> {
+        Ok(self.inside)
+    }
+}
+
+/// A file is being dragged over the window
+#[pyclass(extends = Event, module = "piston2d.window.events")]
+pub struct FileHover {
+    path: String,
+}
+
+#[pymethods]
+impl FileHover {
+    #[classattr]
+    #[name = "__match_args__"]
+    fn match_args() -> (&'static str,) {
+        ("path",)
+    }
+
+    /// The path of the file
+    #[getter]
+    fn path(&self) -> PyResult<String> {
+        Ok(self.path.clone())
+    }
+}
+
+/// A file was dropped on the window
+#[pyclass(extends = Event, module = "piston2d.window.events")]
+pub struct FileDrop {
+    path: String,
+}
+
+#[pymethods]
+impl FileDrop {
+    #[classattr]
+    #[name = "__match_args__"]
+    fn match_args() -> (&'static str,) {
+        ("path",)
+    }
+
+    /// The path of the file
+    #[getter]
+    fn path(&self) -> PyResult<String> {
+        Ok(self.path.clone())
+    }
+}
+
+/// A file was dragged over the window, then away again
+#[pyclass(extends = Event, module = "piston2d.window.events")]
+pub struct FileCancel {}
+
+/// The window was closed
+#[pyclass(extends = Event, module = "piston2d.window.events")]
+pub struct Close {}
+
+/// Create `subclass`, with `event` as its :class:`Event`
+fn with_subclass<T>(
+    py: Python,
+    event: PistonEvent,
+    window_id: Option<u64>,
+    subclass: T,
+) -> PyResult<PyObject>
+where
+    T: PyClass + PyTypeInfo<BaseType = Event>,
+    T::BaseLayout: PySizedLayout<Event> + PyBorrowFlagLayout<Event>,
+{
+    let initializer = PyClassInitializer::from(Event {
+        _piston: event,
+        window_id,
+    })
+    .add_subclass(subclass);
+    Ok(Py::new(py, initializer)?.into_py(py))
+}
+
+/// Wrap a piston event in the :class:`Event` subclass for its kind, from the
+/// window with `window_id` if it was received from one. Custom events are
+/// plain :class:`Event` objects.
+pub fn new_event(py: Python, event: PistonEvent, window_id: Option<u64>) -> PyResult<PyObject> {
+    match event.clone() {
+        PistonEvent::Input(input, _) => match input {
+            PistonInput::Button(args) => {
+                let button = args.button;
+                let scancode = args.scancode;
+                match (args.state, button) {
+                    (ButtonState::Press, PistonButton::Keyboard(key)) => {
+                        with_subclass(py, event, window_id, KeyPress { key, scancode })
+                    }
+                    (ButtonState::Release, PistonButton::Keyboard(key)) => {
+                        with_subclass(py, event, window_id, KeyRelease { key, scancode })
+                    }
+                    (ButtonState::Press, PistonButton::Mouse(button)) => {
+                        with_subclass(py, event, window_id, MousePress { button })
+                    }
+                    (ButtonState::Release, PistonButton::Mouse(button)) => {
+                        with_subclass(py, event, window_id, MouseRelease { button })
+                    }
+                    (ButtonState::Press, _) => {
+                        with_subclass(py, event, window_id, ButtonPress { button })
+                    }
+                    (ButtonState::Release, _) => {
+                        with_subclass(py, event, window_id, ButtonRelease { button })
+                    }
+                }
+            }
+            PistonInput::Move(motion) => match motion {
+                PistonMotion::MouseCursor(position) => {
+                    with_subclass(py, event, window_id, MouseMove { position })
+                }
+                PistonMotion::MouseRelative(delta) => {
+                    with_subclass(py, event, window_id, MouseRelative { delta })
+                }
+                PistonMotion::MouseScroll(delta) => {
+                    with_subclass(py, event, window_id, MouseScroll { delta })
+                }
+                PistonMotion::ControllerAxis(args) => {
+                    with_subclass(py, event, window_id, ControllerAxis { args })
+                }
+                PistonMotion::Touch(args) => with_subclass(py, event, window_id, Touch { args }),
+            },
+            PistonInput::Text(text) => with_subclass(py, event, window_id, Text { text }),
+            PistonInput::Resize(args) => with_subclass(
+                py,
+                event,
+                window_id,
+                Resize {
+                    window_size: args.window_size,
+                    draw_size: args.draw_size,
+                },
+            ),
+            PistonInput::Focus(focused) => with_subclass(py, event, window_id, Focus { focused }),
+            PistonInput::Cursor(inside) => with_subclass(py, event, window_id, Cursor { inside }),
+            PistonInput::FileDrag(PistonFileDrag::Hover(path)) => {
+                let path = path.to_string_lossy().into_owned();
+                with_subclass(py, event, window_id, FileHover { path })
+            }
+            PistonInput::FileDrag(PistonFileDrag::Drop(path)) => {
+                let path = path.to_string_lossy().into_owned();
+                with_subclass(py, event, window_id, FileDrop { path })
+            }
+            PistonInput::FileDrag(PistonFileDrag::Cancel) => {
+                with_subclass(py, event, window_id, FileCancel {})
+            }
+            PistonInput::Close(_) => with_subclass(py, event, window_id, Close {}),
+        },
+        PistonEvent::Loop(event_loop) => match event_loop {
+            PistonLoop::Render(args) => with_subclass(py, event, window_id, Render { args }),
+            PistonLoop::AfterRender(_) => with_subclass(py, event, window_id, AfterRender {}),
+            PistonLoop::Update(args) => with_subclass(py, event, window_id, Update { dt: args.dt }),
+            PistonLoop::Idle(args) => with_subclass(py, event, window_id, Idle { dt: args.dt }),
+        },
+        PistonEvent::Custom(..) => Ok(Py::new(
+            py,
+            Event {
+                _piston: event,
+                window_id,
+            },
+        )?
+        .into_py(py)),
+    }
+}
+
+pub fn init_submodule(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_class::<EventKind>()?;
+    m.add_class::<Render>()?;
+    m.add_class::<AfterRender>()?;
+    m.add_class::<Update>()?;
+    m.add_class::<Idle>()?;
+    m.add_class::<KeyPress>()?;
+    m.add_class::<KeyRelease>()?;
+    m.add_class::<MousePress>()?;
+    m.add_class::<MouseRelease>()?;
+    m.add_class::<ButtonPress>()?;
+    m.add_class::<ButtonRelease>()?;
+    m.add_class::<MouseMove>()?;
+    m.add_class::<MouseRelative>()?;
+    m.add_class::<MouseScroll>()?;
+    m.add_class::<ControllerAxis>()?;
+    m.add_class::<Touch>()?;
+    m.add_class::<Text>()?;
+    m.add_class::<Resize>()?;
+    m.add_class::<Focus>()?;
+    m.add_class::<Cursor>()?;
+    m.add_class::<FileHover>()?;
+    m.add_class::<FileDrop>()?;
+    m.add_class::<FileCancel>()?;
+    m.add_class::<Close>()?;
+
+    Ok(())
+}
@@ -0,0 +1,183 @@
+use pyo3::{exceptions::PyValueError, prelude::*, PyIterProtocol};
+
+use piston::{
+    Event as PistonEvent, EventSettings as PistonEventSettings, Events as PistonEvents, Loop,
+};
+
+use super::{new_event, next_event, EventSettings, Window};
+
+/// Runs an event loop for each of several windows, taking turns between them,
+/// so they can all be driven from one loop.
+///
+/// Events have the :attr:`Event.window_id` of the window they are for. A
+/// window is made current before its :class:`Render` events are returned, so
+/// drawing goes to the right window. Windows are removed once their loop
+/// ends, such as when they are closed.
+///
+/// Loop events, such as :class:`Render`, and input pushed with
+/// :meth:`Window.push_event` are always for their window. Input from the
+/// display is only known to be for its window while no other real window is
+/// open, and otherwise has a ``window_id`` of ``None``: on X11 the windows
+/// share one connection to the display, and which window the input was for
+/// is not kept.
+#[pyclass(module = "piston2d.window.events")]
+pub struct MultiEvents {
+    settings: PistonEventSettings,
+    loops: Vec<(Py<Window>, PistonEvents)>,
+    turn: usize,
+}
+
+impl MultiEvents {
+    /// The index of the loop for the window with `id`
+    fn position(&self, py: Python, id: u64) -> PyResult<Option<usize>> {
+        for (index, (window, _)) in self.loops.iter().enumerate() {
+            if window.try_borrow(py)?._piston.id() == id {
+                return Ok(Some(index));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+#[pymethods]
+impl MultiEvents {
+    /// MultiEvents(settings: EventSettings, windows: List[Window] = []) -> MultiEvents
+    ///
+    /// Create an event loop for several windows
+    ///
+    /// :param settings: The settings for each window's event loop
+    /// :type settings: EventSettings
+    /// :param windows: The windows to run
+    /// :type windows: List[Window]
+    /// :raises ValueError: If the settings are lazy, as waiting for input on
+    ///     one window would stop the others
+    #[new]
+    #[args(settings, windows = "Vec::new()")]
+    fn new(py: Python, settings: EventSettings, windows: Vec<Py<Window>>) -> PyResult<Self> {
+        if settings._piston.lazy {
+            return Err(PyValueError::new_err(
+                "lazy events wait for input on one window at a time",
+            ));
+        }
+
+        let mut events = MultiEvents {
+            settings: settings._piston,
+            loops: Vec::new(),
+            turn: 0,
+        };
+        for window in windows {
+            events.add(py, window)?;
+        }
+
+        Ok(events)
+    }
+
+    /// The settings each window's event loop is created with
+    ///
+    /// :type: EventSettings
+    #[getter]
+    fn settings(&self) -> PyResult<EventSettings> {
+        Ok(EventSettings {
+            _piston: self.settings,
+        })
+    }
+
+    /// The windows still being run
+    ///
+    /// :type: List[Window]
+    #[getter]
+    fn windows(&self, py: Python) -> PyResult<Vec<Py<Window>>> {
+        Ok(self
+            .loops
+            .iter()
+            .map(|(window, _)| window.clone_ref(py))
+            .collect())
+    }
+
+    /// add(window: Window) -> None
+    ///
+    /// Start running a window, with a new event loop
+    ///
+    /// :param window: The window to run
+    /// :type window: Window
+    /// :raises ValueError: If the window is already being run
+    #[args(window)]
+    fn add(&mut self, py: Python, window: Py<Window>) -> PyResult<()> {
+        let id = window.try_borrow(py)?._piston.id();
+        if self.position(py, id)?.is_some() {
+            return Err(PyValueError::new_err("the window is already being run"));
+        }
+        self.loops.push((window, PistonEvents::new(self.settings)));
+
+        Ok(())
+    }
+
+    /// remove(window: Window) -> bool
+    ///
+    /// Stop running a window
+    ///
+    /// :param window: The window to stop running
+    /// :type window: Window
+    /// :returns: Whether the window was being run
+    /// :rtype: bool
+    #[args(window)]
+    fn remove(&mut self, py: Python, window: PyRef<Window>) -> PyResult<bool> {
+        match self.position(py, window._piston.id())? {
+            Some(index) => {
+                self.loops.remove(index);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// next() -> Optional[Event]
+    ///
+    /// Get the next event, from the next window in turn
+    ///
+    /// :returns: Next event or ``None`` (when every window's loop has ended)
+    /// :rtype: Optional[Event]
+    fn next(&mut self, py: Python) -> PyResult<Option<PyObject>> {
+        while !self.loops.is_empty() {
+            let index = self.turn % self.loops.len();
+            let (window, events) = &mut self.loops[index];
+            let mut window = window.try_borrow_mut(py)?;
+
+            match next_event(events, &mut window)? {
+                Some(event) => {
+                    self.turn = index + 1;
+                    if let PistonEvent::Loop(Loop::Render(_)) = event {
+                        window._piston.make_current();
+                    }
+                    let window_id = window._piston.source_id(&event);
+                    return new_event(py, event, window_id).map(Some);
+                }
+                None => {
+                    drop(window);
+                    self.loops.remove(index);
+                    self.turn = index;
+                }
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+#[pyproto]
+impl PyIterProtocol for MultiEvents {
+    fn __iter__(slf: PyRef<Self>) -> PyResult<PyRef<Self>> {
+        Ok(slf)
+    }
+
+    fn __next__(mut slf: PyRefMut<Self>) -> PyResult<Option<PyObject>> {
+        Python::with_gil(|py| slf.next(py))
+    }
+}
+
+pub fn init_submodule(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_class::<MultiEvents>()?;
+
+    Ok(())
+}
@@ -0,0 +1,584 @@
+use std::{
+    convert::TryInto,
+    fs::{self, File},
+    io::{BufWriter, Write},
+    time::Instant,
+};
+
+use pyo3::{
+    exceptions::{PyIOError, PyValueError},
+    prelude::*,
+    types::PyDict,
+    PyIterProtocol,
+};
+
+use piston::{
+    AfterRenderArgs, Button as PistonButton, ButtonArgs, ButtonState, CloseArgs,
+    ControllerAxisArgs, ControllerButton, ControllerHat, Event as PistonEvent, FileDrag, HatState,
+    IdleArgs, Input, Key as PistonKey, Loop, Motion, MouseButton as PistonMouseButton, RenderArgs,
+    ResizeArgs, Touch, TouchArgs, UpdateArgs,
+};
+
+use super::{new_event, next_event, unbound_error, Events, Window};
+
+/// The first line of every recording, identifying the format and its version
+const FORMAT: &str = "piston2d-events";
+const VERSION: u32 = 1;
+
+const HAT_STATES: [HatState; 9] = [
+    HatState::Centered,
+    HatState::Up,
+    HatState::Right,
+    HatState::Down,
+    HatState::Left,
+    HatState::RightUp,
+    HatState::RightDown,
+    HatState::LeftUp,
+    HatState::LeftDown,
+];
+
+/// Get a field of a recorded event
+fn field<'p, T: FromPyObject<'p>>(record: &'p PyDict, name: &str) -> PyResult<T> {
+    record
+        .get_item(name)
+        .ok_or_else(|| PyValueError::new_err(format!("recorded event has no '{}'", name)))?
+        .extract()
+}
+
+/// Get a fixed size list field of a recorded event
+fn field_array<'p, T: FromPyObject<'p>, const N: usize>(
+    record: &'p PyDict,
+    name: &str,
+) -> PyResult<[T; N]> {
+    let values: Vec<T> = field(record, name)?;
+    values
+        .try_into()
+        .map_err(|_| PyValueError::new_err(format!("recorded '{}' must have {} values", name, N)))
+}
+
+/// Describe a button event, returning the name of its constructor on
+/// :class:`Event`
+fn encode_button(record: &PyDict, args: &ButtonArgs) -> PyResult<&'static str> {
+    let press = args.state == ButtonState::Press;
+    let name = match args.button {
+        PistonButton::Keyboard(key) => {
+            record.set_item("key", u32::from(key))?;
+            if press {
+                "key_press"
+            } else {
+                "key_release"
+            }
+        }
+        PistonButton::Mouse(button) => {
+            record.set_item("button", u32::from(button))?;
+            if press {
+                "mouse_press"
+            } else {
+                "mouse_release"
+            }
+        }
+        PistonButton::Controller(button) => {
+            record.set_item("controller", button.id)?;
+            record.set_item("button", button.button)?;
+            if press {
+                "button_press"
+            } else {
+                "button_release"
+            }
+        }
+        PistonButton::Hat(hat) => {
+            record.set_item("hat", hat.id)?;
+            record.set_item("state", format!("{:?}", hat.state))?;
+            record.set_item("which", hat.which)?;
+            if press {
+                "button_press"
+            } else {
+                "button_release"
+            }
+        }
+    };
+    record.set_item("scancode", args.scancode)?;
+
+    Ok(name)
+}
+
+/// Describe an event as a dict that can be written as JSON, or ``None`` for
+/// custom events, which cannot be recorded
+fn encode<'p>(py: Python<'p>, time: f64, event: &PistonEvent) -> PyResult<Option<&'p PyDict>> {
+    let record = PyDict::new(py);
+    record.set_item("time", time)?;
+
+    let name = match event {
+        PistonEvent::Input(input, timestamp) => {
+            if let Some(timestamp) = timestamp {
+                record.set_item("timestamp", timestamp)?;
+            }
+            match input {
+                Input::Button(args) => encode_button(record, args)?,
+                Input::Move(Motion::MouseCursor([x, y])) => {
+                    record.set_item("position", vec![x, y])?;
+                    "mouse_move"
+                }
+                Input::Move(Motion::MouseRelative([dx, dy])) => {
+                    record.set_item("delta", vec![dx, dy])?;
+                    "mouse_relative"
+                }
+                Input::Move(Motion::MouseScroll([dx, dy])) => {
+                    record.set_item("delta", vec![dx, dy])?;
+                    "mouse_scroll"
+                }
+                Input::Move(Motion::ControllerAxis(args)) => {
+                    record.set_item("id", args.id)?;
+                    record.set_item("axis", args.axis)?;
+                    record.set_item("position", args.position)?;
+                    "controller_axis"
+                }
+                Input::Move(Motion::Touch(args)) => {
+                    record.set_item("device", args.device)?;
+                    record.set_item("id", args.id)?;
+                    record.set_item("position", args.position_3d.to_vec())?;
+                    record.set_item("pressure", args.pressure_3d.to_vec())?;
+                    record.set_item("is_3d", args.is_3d)?;
+                    record.set_item("touch", format!("{:?}", args.touch))?;
+                    "touch"
+                }
+                Input::Text(text) => {
+                    record.set_item("text", text)?;
+                    "text"
+                }
+                Input::Resize(args) => {
+                    record.set_item("window_size", args.window_size.to_vec())?;
+                    record.set_item("draw_size", args.draw_size.to_vec())?;
+                    "resize"
+                }
+                Input::Focus(focused) => {
+                    record.set_item("focused", focused)?;
+                    "focus"
+                }
+                Input::Cursor(inside) => {
+                    record.set_item("inside", inside)?;
+                    "cursor"
+                }
+                Input::FileDrag(FileDrag::Hover(path)) => {
+                    record.set_item("path", path.to_string_lossy())?;
+                    "file_hover"
+                }
+                Input::FileDrag(FileDrag::Drop(path)) => {
+                    record.set_item("path", path.to_string_lossy())?;
+                    "file_drop"
+                }
+                Input::FileDrag(FileDrag::Cancel) => "file_cancel",
+                Input::Close(_) => "close",
+            }
+        }
+        PistonEvent::Loop(Loop::Render(args)) => {
+            record.set_item("ext_dt", args.ext_dt)?;
+            record.set_item("window_size", args.window_size.to_vec())?;
+            record.set_item("draw_size", args.draw_size.to_vec())?;
+            "render"
+        }
+        PistonEvent::Loop(Loop::AfterRender(_)) => "after_render",
+        PistonEvent::Loop(Loop::Update(args)) => {
+            record.set_item("dt", args.dt)?;
+            "update"
+        }
+        PistonEvent::Loop(Loop::Idle(args)) => {
+            record.set_item("dt", args.dt)?;
+            "idle"
+        }
+        PistonEvent::Custom(..) => return Ok(None),
+    };
+    record.set_item("event", name)?;
+
+    Ok(Some(record))
+}
+
+/// Read back a button event described by [`encode_button`]
+fn decode_button(record: &PyDict, name: &str) -> PyResult<Input> {
+    let state = if name.ends_with("_press") {
+        ButtonState::Press
+    } else {
+        ButtonState::Release
+    };
+    let button = if name.starts_with("key_") {
+        PistonButton::Keyboard(PistonKey::from(field::<u32>(record, "key")?))
+    } else if name.starts_with("mouse_") {
+        PistonButton::Mouse(PistonMouseButton::from(field::<u32>(record, "button")?))
+    } else if record.contains("controller")? {
+        let id = field(record, "controller")?;
+        PistonButton::Controller(ControllerButton::new(id, field(record, "button")?))
+    } else {
+        let state: String = field(record, "state")?;
+        let state = HAT_STATES
+            .iter()
+            .find(|hat| format!("{:?}", hat) == state)
+            .ok_or_else(|| PyValueError::new_err(format!("unknown hat state '{}'", state)))?;
+        PistonButton::Hat(ControllerHat::new(
+            field(record, "hat")?,
+            field(record, "which")?,
+            *state,
+        ))
+    };
+
+    Ok(Input::Button(ButtonArgs {
+        state,
+        button,
+        scancode: field(record, "scancode")?,
+    }))
+}
+
+/// Read back an event described by [`encode`]
+fn decode(record: &PyDict) -> PyResult<PistonEvent> {
+    let name: String = field(record, "event")?;
+    let input = match name.as_str() {
+        "key_press" | "key_release" | "mouse_press" | "mouse_release" | "button_press"
+        | "button_release" => decode_button(record, &name)?,
+        "mouse_move" => Input::Move(Motion::MouseCursor(field_array(record, "position")?)),
+        "mouse_relative" => Input::Move(Motion::MouseRelative(field_array(record, "delta")?)),
+        "mouse_scroll" => Input::Move(Motion::MouseScroll(field_array(record, "delta")?)),
+        "controller_axis" => Input::Move(Motion::ControllerAxis(ControllerAxisArgs::new(
+            field(record, "id")?,
+            field(record, "axis")?,
+            field(record, "position")?,
+        ))),
+        "touch" => {
+            let touch: String = field(record, "touch")?;
+            let touch = match touch.as_str() {
+                "Start" => Touch::Start,
+                "Move" => Touch::Move,
+                "End" => Touch::End,
+                "Cancel" => Touch::Cancel,
+                _ => return Err(PyValueError::new_err(format!("unknown touch '{}'", touch))),
+            };
+            Input::Move(Motion::Touch(TouchArgs {
+                device: field(record, "device")?,
+                id: field(record, "id")?,
+                position_3d: field_array(record, "position")?,
+                pressure_3d: field_array(record, "pressure")?,
+                is_3d: field(record, "is_3d")?,
+                touch,
+            }))
+        }
+        "text" => Input::Text(field(record, "text")?),
+        "resize" => Input::Resize(ResizeArgs {
+            window_size: field_array(record, "window_size")?,
+            draw_size: field_array(record, "draw_size")?,
+        }),
+        "focus" => Input::Focus(field(record, "focused")?),
+        "cursor" => Input::Cursor(field(record, "inside")?),
+        "file_hover" => Input::FileDrag(FileDrag::Hover(field::<String>(record, "path")?.into())),
+        "file_drop" => Input::FileDrag(FileDrag::Drop(field::<String>(record, "path")?.into())),
+        "file_cancel" => Input::FileDrag(FileDrag::Cancel),
+        "close" => Input::Close(CloseArgs),
+        "render" => {
+            return Ok(PistonEvent::Loop(Loop::Render(RenderArgs {
+                ext_dt: field(record, "ext_dt")?,
+                window_size: field_array(record, "window_size")?,
+                draw_size: field_array(record, "draw_size")?,
+            })))
+        }
+        "after_render" => return Ok(PistonEvent::Loop(Loop::AfterRender(AfterRenderArgs))),
+        "update" => {
+            return Ok(PistonEvent::Loop(Loop::Update(UpdateArgs {
+                dt: field(record, "dt")?,
+            })))
+        }
+        "idle" => {
+            return Ok(PistonEvent::Loop(Loop::Idle(IdleArgs {
+                dt: field(record, "dt")?,
+            })))
+        }
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "unknown recorded event '{}'",
+                name
+            )))
+        }
+    };
+
+    let timestamp = match record.get_item("timestamp") {
+        Some(timestamp) => Some(timestamp.extract()?),
+        None => None,
+    };
+    Ok(PistonEvent::Input(input, timestamp))
+}
+
+/// Records the events from an :class:`Events` to a file as they are received,
+/// so they can be replayed later with :class:`EventPlayer`.
+///
+/// Each event is written as a line of JSON, along with the time it was
+/// received in seconds since recording started. Lines are written as they
+/// are received, so a recording keeps the events before a crash. Custom
+/// events are not recorded.
+#[pyclass(module = "piston2d.window.events")]
+pub struct EventRecorder {
+    events: Py<Events>,
+    path: String,
+    writer: Option<BufWriter<File>>,
+    start: Instant,
+}
+
+impl EventRecorder {
+    fn write(&mut self, py: Python, event: &PistonEvent) -> PyResult<()> {
+        let time = self.start.elapsed().as_secs_f64();
+        let writer = self
+            .writer
+            .as_mut()
+            .ok_or_else(|| PyValueError::new_err("the recorder is closed"))?;
+        if let Some(record) = encode(py, time, event)? {
+            let line: String = py
+                .import("json")?
+                .call_method1("dumps", (record,))?
+                .extract()?;
+            writeln!(writer, "{}", line)
+                .and_then(|_| writer.flush())
+                .map_err(|e| {
+                    PyIOError::new_err(format!("could not write to '{}': {}", self.path, e))
+                })?;
+        }
+
+        Ok(())
+    }
+}
+
+#[pymethods]
+impl EventRecorder {
+    /// EventRecorder(events: Events, path: str) -> EventRecorder
+    ///
+    /// Start recording to a file, replacing it if it exists
+    ///
+    /// :param events: The events to record
+    /// :type events: Events
+    /// :param path: The file to record to
+    /// :type path: str
+    #[new]
+    #[args(events, path)]
+    fn new(py: Python, events: Py<Events>, path: String) -> PyResult<Self> {
+        let file = File::create(&path)
+            .map_err(|e| PyIOError::new_err(format!("could not create '{}': {}", path, e)))?;
+        let mut writer = BufWriter::new(file);
+
+        let header = PyDict::new(py);
+        header.set_item("format", FORMAT)?;
+        header.set_item("version", VERSION)?;
+        let header: String = py
+            .import("json")?
+            .call_method1("dumps", (header,))?
+            .extract()?;
+        writeln!(writer, "{}", header)
+            .map_err(|e| PyIOError::new_err(format!("could not write to '{}': {}", path, e)))?;
+
+        Ok(EventRecorder {
+            events,
+            path,
+            writer: Some(writer),
+            start: Instant::now(),
+        })
+    }
+
+    /// The events being recorded
+    ///
+    /// :type: Events
+    #[getter]
+    fn events(&self) -> PyResult<Py<Events>> {
+        Ok(self.events.clone())
+    }
+
+    /// The file being recorded to
+    ///
+    /// :type: str
+    #[getter]
+    fn path(&self) -> PyResult<String> {
+        Ok(self.path.clone())
+    }
+
+    /// Whether the recorder has been closed
+    ///
+    /// :type: bool
+    #[getter]
+    fn closed(&self) -> PyResult<bool> {
+        Ok(self.writer.is_none())
+    }
+
+    /// next(window: Window) -> Optional[Event]
+    ///
+    /// Get the next event, as :meth:`Events.next` does, and record it
+    ///
+    /// :param window: The window to run on
+    /// :type window: Window
+    /// :raises ValueError: If the recorder is closed
+    /// :returns: Next event or ``None`` (when ending event loop)
+    /// :rtype: Optional[Event]
+    #[args(window)]
+    fn next(&mut self, py: Python, window: &mut Window) -> PyResult<Option<PyObject>> {
+        if self.writer.is_none() {
+            return Err(PyValueError::new_err("the recorder is closed"));
+        }
+
+        let event = next_event(&mut self.events.try_borrow_mut(py)?._piston, window)?;
+        match event {
+            Some(event) => {
+                self.write(py, &event)?;
+                let window_id = window._piston.source_id(&event);
+                new_event(py, event, window_id).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// close()
+    ///
+    /// Finish writing the recording. Closing a closed recorder does nothing.
+    fn close(&mut self) -> PyResult<()> {
+        if let Some(mut writer) = self.writer.take() {
+            writer.flush().map_err(|e| {
+                PyIOError::new_err(format!("could not write to '{}': {}", self.path, e))
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+#[pyproto]
+impl PyIterProtocol for EventRecorder {
+    fn __iter__(slf: PyRef<Self>) -> PyResult<PyRef<Self>> {
+        if slf.events.borrow(slf.py()).window.is_none() {
+            return Err(unbound_error());
+        }
+        Ok(slf)
+    }
+
+    fn __next__(mut slf: PyRefMut<Self>) -> PyResult<Option<PyObject>> {
+        Python::with_gil(|py| {
+            let window = slf
+                .events
+                .borrow(py)
+                .window
+                .clone()
+                .ok_or_else(unbound_error)?;
+            let mut window = window.try_borrow_mut(py)?;
+            slf.next(py, &mut window)
+        })
+    }
+}
+
+/// Replays events recorded by :class:`EventRecorder`, in the order and with
+/// the timing they were recorded with.
+///
+/// Loop events keep their recorded timing, such as :attr:`Update.dt`, so a
+/// replay is the same however fast it runs.
+#[pyclass(module = "piston2d.window.events")]
+pub struct EventPlayer {
+    lines: Vec<String>,
+    position: usize,
+    time: f64,
+}
+
+#[pymethods]
+impl EventPlayer {
+    /// EventPlayer(path: str) -> EventPlayer
+    ///
+    /// Load a recording
+    ///
+    /// :param path: The file recorded to
+    /// :type path: str
+    /// :raises ValueError: If the file is not a recording
+    #[new]
+    #[args(path)]
+    fn new(py: Python, path: &str) -> PyResult<Self> {
+        let data = fs::read_to_string(path)
+            .map_err(|e| PyIOError::new_err(format!("could not load '{}': {}", path, e)))?;
+        let mut lines = data.lines().filter(|line| !line.trim().is_empty());
+
+        let not_recording = || PyValueError::new_err(format!("'{}' is not a recording", path));
+        let header = lines.next().ok_or_else(not_recording)?;
+        let header: &PyDict = py
+            .import("json")?
+            .call_method1("loads", (header,))
+            .and_then(|header| Ok(header.downcast::<PyDict>()?))
+            .map_err(|_| not_recording())?;
+        if field::<String>(header, "format").ok().as_deref() != Some(FORMAT) {
+            return Err(not_recording());
+        }
+        let version: u32 = field(header, "version")?;
+        if version > VERSION {
+            return Err(PyValueError::new_err(format!(
+                "'{}' was recorded with a newer version ({})",
+                path, version
+            )));
+        }
+
+        Ok(EventPlayer {
+            lines: lines.map(String::from).collect(),
+            position: 0,
+            time: 0.0,
+        })
+    }
+
+    /// The time the last event was recorded at, in seconds since recording
+    /// started
+    ///
+    /// :type: float
+    #[getter]
+    fn time(&self) -> PyResult<f64> {
+        Ok(self.time)
+    }
+
+    /// The number of events not yet replayed
+    ///
+    /// :type: int
+    #[getter]
+    fn remaining(&self) -> PyResult<usize> {
+        Ok(self.lines.len() - self.position)
+    }
+
+    /// next(window: Optional[Window] = None) -> Optional[Event]
+    ///
+    /// Get the next recorded event, so a player can be used in place of
+    /// :class:`Events`. Events are only returned, not sent to the window.
+    ///
+    /// :param window: The window being replayed to, whose
+    ///     :attr:`Window.id` is the :attr:`Event.window_id` of the event
+    /// :type window: Optional[Window]
+    /// :raises ValueError: If the recorded event is invalid
+    /// :returns: Next event or ``None`` (when the recording ends)
+    /// :rtype: Optional[Event]
+    #[args(window = "None")]
+    fn next(&mut self, py: Python, window: Option<PyRef<Window>>) -> PyResult<Option<PyObject>> {
+        let line = match self.lines.get(self.position) {
+            Some(line) => line,
+            None => return Ok(None),
+        };
+
+        let record: &PyDict = py
+            .import("json")?
+            .call_method1("loads", (line.as_str(),))?
+            .downcast()
+            .map_err(|_| PyValueError::new_err("recorded event is not an object"))?;
+        let event = decode(record)?;
+        self.time = field(record, "time")?;
+        self.position += 1;
+
+        let window_id = window.map(|window| window._piston.id());
+        new_event(py, event, window_id).map(Some)
+    }
+}
+
+#[pyproto]
+impl PyIterProtocol for EventPlayer {
+    fn __iter__(slf: PyRef<Self>) -> PyResult<PyRef<Self>> {
+        Ok(slf)
+    }
+
+    fn __next__(mut slf: PyRefMut<Self>) -> PyResult<Option<PyObject>> {
+        Python::with_gil(|py| slf.next(py, None))
+    }
+}
+
+pub fn init_submodule(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_class::<EventRecorder>()?;
+    m.add_class::<EventPlayer>()?;
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+import traceback
+
+import piston2d
+
+app = piston2d.init("test", (100, 100))
+errors = []
+
+
+def broken(args):
+    raise ValueError("broken handler")
+
+
+app.update(broken)
+
+# Without an error handler the exception comes out of tick
+try:
+    while True:
+        app.tick()
+except ValueError as e:
+    frames = traceback.extract_tb(e.__traceback__)
+    assert frames[-1].name == "broken"
+
+# With one, ticking carries on
+app.on_error(errors.append)
+for _ in range(100):
+    app.tick()
+
+assert errors and all(isinstance(e, ValueError) for e in errors)
+
+try:
+    app.on_error(42)
+    raise AssertionError("non-callable error handler accepted")
+except TypeError:
+    pass
+
+print("App errors OK")
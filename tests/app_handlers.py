@@ -0,0 +1,63 @@
+import piston2d
+
+app = piston2d.init("test", (100, 100))
+calls = []
+
+
+@app.on_update
+def count(args):
+    calls.append(args.dt)
+
+
+@app.on_update
+def once(args):
+    calls.append("once")
+    once.remove()
+
+
+@app.on_key_press
+def key(button):
+    print("Key pressed:", button.key)
+
+
+@app.on_resize
+def resize(width, height):
+    print("Resized to", width, height)
+
+
+for _ in range(100):
+    app.tick()
+
+assert calls.count("once") == 1
+assert not once.registered and count.registered
+assert count.remove() and not count.remove()
+
+# Decorated functions are still available, and callable
+assert key.__wrapped__.__name__ == "key"
+resize(1, 2)
+
+
+# Methods are registered bound, as handlers do not bind to instances
+class Player:
+    def __init__(self):
+        self.updates = 0
+        self.handler = app.on_update(self.update)
+
+    def update(self, args):
+        self.updates += 1
+
+
+player = Player()
+for _ in range(100):
+    app.tick()
+assert player.updates > 0
+assert player.handler.__wrapped__.__self__ is player
+player.handler.remove()
+
+try:
+    app.on_text("not callable")
+    raise AssertionError("non-callable handler accepted")
+except TypeError:
+    pass
+
+print("App handlers OK")
@@ -0,0 +1,35 @@
+import piston2d
+
+app = piston2d.init("test", (100, 100))
+
+
+@app.on_key_press
+def key_press(button):
+    assert app.is_key_down(button) and app.is_key_down(button.key)
+    assert app.is_key_down(button.value())
+    assert button.key in app.keys_down()
+
+
+@app.on_mouse_press
+def mouse_press(button):
+    assert app.is_mouse_down(button) and app.is_mouse_down(button.mouse)
+    assert button.mouse in app.mouse_buttons_down()
+
+
+@app.on_update
+def update(args):
+    for key in app.keys_down():
+        if app.was_pressed(key):
+            print("Pressed", key, "at", app.cursor_position())
+    if app.scroll_delta() != [0.0, 0.0]:
+        print("Scrolled", app.scroll_delta())
+
+
+try:
+    app.is_mouse_down("left")
+    raise AssertionError("invalid button accepted")
+except TypeError:
+    pass
+
+print("Press keys, click and scroll, then close the window")
+app.run()
@@ -0,0 +1,33 @@
+import piston2d
+
+app = piston2d.init("test", (100, 100))
+calls = []
+
+
+@app.on_start
+def start():
+    calls.append("start")
+
+
+@app.on_update
+def update(args):
+    calls.append("update")
+    if calls.count("update") == 10:
+        app.stop()
+
+
+@app.on_exit
+def exit():
+    calls.append("exit")
+
+
+app.run()
+
+assert calls[0] == "start" and calls[-1] == "exit"
+assert calls.count("update") == 10
+
+print("App run OK, press Ctrl+C to close the window")
+calls.clear()
+update.remove()
+app.run()
+assert calls == ["start", "exit"]
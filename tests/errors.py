@@ -0,0 +1,54 @@
+import piston2d
+from piston2d.errors import (InvalidTransformError, Piston2dError,
+                             UnsupportedOpenGLVersion)
+from piston2d.graphics import Matrix2d, rectangle
+from piston2d.opengl import GlGraphics
+from piston2d.software import SoftwareGraphics
+
+from piston2d.piston2d import errors as native_errors
+
+# Named from piston2d.errors by the extension itself, not only once the
+# Python wrapper is imported
+for name in ("Piston2dError", "WindowCreationError", "WindowClosedError",
+             "InvalidTransformError", "UnsupportedOpenGLVersion"):
+    error = getattr(native_errors, name)
+    assert error.__module__ == "piston2d.errors", error.__module__
+    assert error.__qualname__ == name
+assert issubclass(native_errors.WindowClosedError, Piston2dError)
+
+graphics = SoftwareGraphics(4, 4)
+
+for transform in ([[1, 0, 0]], [[1, 0], [0, 1]], [[1, 0, 0], "row"],
+                  [[1, 0, 0], [0, "one", 0]]):
+    try:
+        rectangle([1.0, 1.0, 1.0, 1.0], [0, 0, 1, 1], transform, graphics)
+        raise AssertionError("invalid transform accepted: %r" % (transform,))
+    except InvalidTransformError as e:
+        print(e)
+
+try:
+    Matrix2d([[1, 0, 0]])
+    raise AssertionError("invalid matrix accepted")
+except InvalidTransformError:
+    pass
+
+try:
+    GlGraphics("1.0")
+    raise AssertionError("invalid OpenGL version accepted")
+except UnsupportedOpenGLVersion as e:
+    assert isinstance(e, Piston2dError)
+    print(e)
+
+try:
+    piston2d.init("test", (100, 100), opengl="1.0")
+    raise AssertionError("invalid OpenGL version accepted")
+except UnsupportedOpenGLVersion:
+    pass
+
+try:
+    piston2d.init(size=(100, 100))
+    raise AssertionError("init without a title accepted")
+except TypeError:
+    pass
+
+print("Errors OK")
@@ -0,0 +1,32 @@
+from piston2d.window import Window, WindowSettings
+from piston2d.window.events import Events, EventSettings
+
+window = Window(WindowSettings("event args", (200, 200)))
+events = Events(EventSettings())
+
+while event := events.next(window):
+    if args := event.mouse_cursor_args():
+        print("cursor", args.x, args.y)
+    if args := event.mouse_relative_args():
+        print("relative", args.delta)
+    if args := event.mouse_scroll_args():
+        print("scroll", args.dx, args.dy)
+    if args := event.text_args():
+        print("text", repr(args.text))
+    if args := event.resize_args():
+        print("resize", args.window_size, args.draw_size)
+    if args := event.focus_args():
+        print("focus", args.focused)
+    if args := event.cursor_args():
+        print("cursor inside", args.inside)
+    if args := event.controller_axis_args():
+        print("axis", args.id, args.axis, args.position)
+    if args := event.touch_args():
+        print("touch", args.touch, args.position, args.pressure)
+    if args := event.file_drag_args():
+        print("file", args.kind, args.path)
+    if event.close_args():
+        print("close")
+
+    assert event.idle_args() is None or event.idle_args().dt >= 0
+    assert event.after_render_args() is None or event.render_args() is None
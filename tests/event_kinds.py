@@ -0,0 +1,51 @@
+from piston2d.window import events
+from piston2d.window.events import Event, EventKind
+
+kinds = ["RENDER", "AFTER_RENDER", "UPDATE", "IDLE", "KEY_PRESS", "KEY_RELEASE",
+         "MOUSE_PRESS", "MOUSE_RELEASE", "BUTTON_PRESS", "BUTTON_RELEASE",
+         "MOUSE_MOVE", "MOUSE_RELATIVE", "MOUSE_SCROLL", "CONTROLLER_AXIS",
+         "TOUCH", "TEXT", "RESIZE", "FOCUS", "CURSOR", "FILE_HOVER", "FILE_DROP",
+         "FILE_CANCEL", "CLOSE", "CUSTOM"]
+
+for name in kinds:
+    kind = getattr(EventKind, name)
+    assert kind.name == name
+    assert repr(kind) == "EventKind." + name
+    assert kind == getattr(EventKind, name)
+
+assert len({getattr(EventKind, name) for name in kinds}) == len(kinds)
+assert EventKind.KEY_PRESS != EventKind.KEY_RELEASE
+assert EventKind.TEXT != "TEXT"
+
+match_args = {
+    "Render": ("ext_dt", "window_size", "draw_size"),
+    "Update": ("dt",),
+    "Idle": ("dt",),
+    "KeyPress": ("key", "scancode"),
+    "KeyRelease": ("key", "scancode"),
+    "MousePress": ("button",),
+    "MouseRelease": ("button",),
+    "ButtonPress": ("button",),
+    "ButtonRelease": ("button",),
+    "MouseMove": ("x", "y"),
+    "MouseRelative": ("dx", "dy"),
+    "MouseScroll": ("dx", "dy"),
+    "ControllerAxis": ("id", "axis", "position"),
+    "Touch": ("touch", "position", "pressure"),
+    "Text": ("text",),
+    "Resize": ("window_size", "draw_size"),
+    "Focus": ("focused",),
+    "Cursor": ("inside",),
+    "FileHover": ("path",),
+    "FileDrop": ("path",),
+}
+
+for name, args in match_args.items():
+    cls = getattr(events, name)
+    assert issubclass(cls, Event)
+    assert cls.__match_args__ == args, name
+
+for name in ("AfterRender", "FileCancel", "Close"):
+    assert issubclass(getattr(events, name), Event)
+
+print("ok")
@@ -0,0 +1,27 @@
+from piston2d.window import Window, WindowSettings
+from piston2d.window.events import (Close, Event, EventKind, Events,
+                                    EventSettings, KeyPress, MouseMove, Render,
+                                    Resize, Text, Update)
+
+window = Window(WindowSettings("event match", (200, 200)))
+
+for event in Events(EventSettings(), window):
+    assert isinstance(event, Event)
+
+    match event:
+        case Render(ext_dt, window_size):
+            assert event.kind == EventKind.RENDER
+            assert event.render_args() is not None
+        case Update(dt):
+            assert event.kind == EventKind.UPDATE
+            assert dt == event.update_args().dt
+        case KeyPress(key):
+            print("pressed", key)
+        case MouseMove(x, y):
+            print("cursor", x, y)
+        case Text(text):
+            print("text", repr(text))
+        case Resize(window_size, draw_size):
+            print("resize", window_size, draw_size)
+        case Close():
+            print("close")
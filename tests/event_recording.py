@@ -0,0 +1,139 @@
+import os
+import tempfile
+
+from piston2d.input import ControllerButton, Key, MouseButton
+from piston2d.window import Window, WindowSettings
+from piston2d.window.events import (Event, EventPlayer, EventRecorder, Events,
+                                    EventSettings, FileDrop, KeyPress,
+                                    MouseMove, MousePress, Render, Resize,
+                                    Update)
+
+path = os.path.join(tempfile.mkdtemp(), "events.jsonl")
+
+window = Window(WindowSettings("recording", (64, 64), headless=True))
+pushed = [
+    Event.key_press(Key.A, 30),
+    Event.mouse_press(MouseButton.Right),
+    Event.button_release(ControllerButton(1, 4)),
+    Event.mouse_move(10.5, 20),
+    Event.mouse_scroll(0, -2),
+    Event.controller_axis(0, 1, 0.25),
+    Event.text("hello"),
+    Event.resize(128, 96),
+    Event.focus(False),
+    Event.cursor(True),
+    Event.file_drop("/tmp/image.png"),
+    Event.close(),
+]
+for event in pushed:
+    window.push_event(event)
+
+# Record everything an event loop produces
+recorder = EventRecorder(Events(EventSettings(max_fps=1000, ups=1000)), path)
+assert recorder.path == path and not recorder.closed
+original = []
+while len(original) < 100:
+    original.append(recorder.next(window))
+
+# Lines are written as they are received, not only once closed
+with open(path) as f:
+    assert len(f.read().splitlines()) == 101
+recorder.close()
+recorder.close()
+assert recorder.closed
+try:
+    recorder.next(window)
+    raise AssertionError("recorded to a closed recorder")
+except ValueError as e:
+    print(e)
+
+assert any(isinstance(event, Render) for event in original)
+assert any(isinstance(event, Update) for event in original)
+
+# The replay is the same events, in the same order, with the same timing
+player = EventPlayer(path)
+assert player.remaining == len(original)
+replayed = list(player)
+assert player.remaining == 0 and player.next() is None
+assert player.time > 0
+
+assert len(replayed) == len(original)
+for a, b in zip(original, replayed):
+    assert type(a) is type(b), (a, b)
+    if isinstance(a, Update):
+        assert a.dt == b.dt
+    if isinstance(a, Render):
+        assert a.ext_dt == b.ext_dt and a.draw_size == b.draw_size
+
+by_type = {type(event): event for event in replayed}
+assert by_type[KeyPress].key == Key.A and by_type[KeyPress].scancode == 30
+assert by_type[MousePress].button == MouseButton.Right
+assert by_type[MouseMove].position == [10.5, 20.0]
+assert by_type[Resize].window_size == [128.0, 96.0]
+assert by_type[FileDrop].path == "/tmp/image.png"
+release = [e for e in replayed if e.release_args() is not None][0]
+assert release.release_args().controller == ControllerButton(1, 4)
+
+# Replayed events are from no window, as the recorded ids were only unique
+# to the recording process
+assert all(event.window_id is None for event in replayed)
+
+# The player can stand in for Events, with events from the window given
+target = Window(WindowSettings("replay", (64, 64), headless=True))
+player = EventPlayer(path)
+count = 0
+while (event := player.next(target)) is not None:
+    assert event.window_id == target.id
+    count += 1
+assert count == len(original)
+
+# Events are only returned, not pushed to the window as well
+assert target.wait_event_timeout(0) is None
+
+# Bound events can be recorded by iterating
+events = Events(EventSettings(max_fps=1000, ups=1000), window)
+recorder = EventRecorder(events, path)
+for count, event in enumerate(recorder):
+    if count == 20:
+        break
+recorder.close()
+assert EventPlayer(path).remaining == 21
+
+try:
+    iter(EventRecorder(Events(EventSettings()), path))
+    raise AssertionError("iterated a recorder with unbound events")
+except TypeError as e:
+    print(e)
+
+# Files that are not recordings are rejected
+with open(path, "w") as f:
+    f.write('{"hello": "world"}\n')
+try:
+    EventPlayer(path)
+    raise AssertionError("played a file that is not a recording")
+except ValueError as e:
+    print(e)
+
+with open(path, "w") as f:
+    f.write('{"format": "piston2d-events", "version": 1}\n{"event": "jump"}\n')
+try:
+    EventPlayer(path).next()
+    raise AssertionError("played an unknown event")
+except ValueError as e:
+    print(e)
+
+with open(path, "w") as f:
+    f.write('{"format": "piston2d-events", "version": 1}\n[1, 2]\n')
+try:
+    EventPlayer(path).next()
+    raise AssertionError("played a recorded event that is not an object")
+except ValueError as e:
+    print(e)
+
+try:
+    EventPlayer(os.path.join(path, "missing"))
+    raise AssertionError("played a missing file")
+except OSError as e:
+    print(e)
+
+print("ok")
@@ -0,0 +1,56 @@
+from piston2d.window.events import Events, EventSettings
+
+settings = EventSettings()
+assert (settings.max_fps, settings.ups, settings.ups_reset) == (60, 120, 2)
+assert settings.swap_buffers and not settings.bench_mode and not settings.lazy
+
+settings = EventSettings(max_fps=30, ups=0, lazy=True)
+assert (settings.max_fps, settings.ups, settings.ups_reset) == (30, 0, 2)
+assert settings.lazy
+
+settings.max_fps = 144
+settings.ups = 240
+settings.ups_reset = 0
+settings.swap_buffers = False
+settings.bench_mode = True
+settings.lazy = False
+assert (settings.max_fps, settings.ups, settings.ups_reset) == (144, 240, 0)
+assert not settings.swap_buffers and settings.bench_mode and not settings.lazy
+
+for bad in (lambda: EventSettings(max_fps=0),
+            lambda: setattr(settings, "max_fps", 0)):
+    try:
+        bad()
+        raise AssertionError("max_fps of 0 accepted")
+    except ValueError as e:
+        print(e)
+
+events = Events(EventSettings())
+events.set_max_fps(30)
+events.set_ups(60)
+events.set_ups_reset(5)
+events.set_swap_buffers(False)
+events.set_bench_mode(True)
+events.set_lazy(True)
+current = events.settings
+assert (current.max_fps, current.ups, current.ups_reset) == (30, 60, 5)
+assert not current.swap_buffers and current.bench_mode and current.lazy
+
+try:
+    events.set_max_fps(0)
+    raise AssertionError("max_fps of 0 accepted")
+except ValueError:
+    pass
+
+events.settings = EventSettings(ups=10)
+assert events.settings.ups == 10 and events.settings.max_fps == 60
+
+assert events.window is None
+for unbound in (lambda: iter(events), lambda: next(events)):
+    try:
+        unbound()
+        raise AssertionError("iterated over events without a window")
+    except TypeError as e:
+        print(e)
+
+print("ok")
@@ -0,0 +1,20 @@
+from piston2d.window import Window, WindowSettings
+from piston2d.window.events import Event, Events, EventSettings
+
+window = Window(WindowSettings("events iter", (200, 200)))
+
+events = Events(EventSettings(), window)
+assert events.window is window
+assert iter(events) is events
+
+for count, event in enumerate(events):
+    assert isinstance(event, Event)
+    if count == 10:
+        window.set_should_close(True)
+
+# piston's loop has ended, so the iterator stays exhausted
+assert next(events, None) is None
+
+other = Events(EventSettings())
+assert other.iter(window) is other
+print("ok")
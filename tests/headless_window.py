@@ -0,0 +1,72 @@
+import os
+
+import piston2d
+from piston2d.errors import WindowCreationError
+from piston2d.window import Window, WindowSettings
+from piston2d.window.events import (Events, EventSettings, Idle, Render,
+                                    Update)
+
+settings = WindowSettings("headless", (64, 32), headless=True)
+assert settings.headless
+
+window = Window(settings)
+assert window.headless
+assert window.title == "headless" and window.size == [64, 32]
+assert window.draw_size == (64.0, 32.0)
+assert not window.is_current()
+window.make_current()
+
+window.size = (128, 64)
+assert window.size == [128, 64]
+
+# No display is needed to run the event loop
+events = Events(EventSettings(max_fps=1000, ups=1000), window)
+counts = {Render: 0, Update: 0, Idle: 0}
+for count, event in enumerate(events):
+    for kind in counts:
+        if isinstance(event, kind):
+            counts[kind] += 1
+    if count == 200:
+        window.should_close = True
+
+assert counts[Render] and counts[Update], counts
+assert next(events, None) is None
+
+# Nothing would ever arrive
+assert window.wait_event_timeout(0.01) is None
+try:
+    window.wait_event()
+    raise AssertionError("waited for an event that can never arrive")
+except ValueError as e:
+    print(e)
+
+try:
+    Events(EventSettings(lazy=True)).next(Window(settings))
+    raise AssertionError("lazy events ran on a headless window")
+except ValueError as e:
+    print(e)
+
+try:
+    piston2d.init(settings=settings)
+    raise AssertionError("app created with a headless window")
+except WindowCreationError as e:
+    print(e)
+
+# Timeouts that are not a duration are rejected, rather than panicking
+for seconds in (-1, float("nan"), float("inf"), 1e300):
+    try:
+        window.wait_event_timeout(seconds)
+        raise AssertionError("waited for %r seconds" % seconds)
+    except ValueError as e:
+        print(e)
+
+# Without a display, the window fails before any OpenGL context is created, so
+# 2.1 is not tried after 3.2
+if not os.environ.get("DISPLAY") and not os.environ.get("WAYLAND_DISPLAY"):
+    try:
+        piston2d.init("no display", (64, 32))
+        raise AssertionError("window created without a display")
+    except WindowCreationError as e:
+        assert "2.1" not in str(e), e
+
+print("ok")
@@ -0,0 +1,33 @@
+from piston2d.input import ControllerButton, Key, Mouse, MouseButton
+from piston2d.window.events import Event
+
+assert Key.A == Key(Key.A.code) and Key.A != Key.B
+assert Key.A.name == "A" and Key.Space.code == 0x20
+assert repr(Key.Return) == "Key.Return"
+assert Key(0x12345678) == Key.Unknown
+assert len({Key.A, Key(Key.A.code), Key.B}) == 2
+
+assert MouseButton.Left.code == 1 and MouseButton(3) == MouseButton.Middle
+assert repr(MouseButton.X1) == "MouseButton.X1"
+assert Mouse is MouseButton
+
+# Codes collide between keys and mouse buttons, the classes do not
+assert Key(1) != MouseButton(1)
+assert Key.Unknown != MouseButton.Unknown
+
+# Variants used to be int codes, and still work as them
+assert Key.A == 0x61 and 0x61 == Key.A and Key.A != 0x62
+assert int(Key.Space) == 0x20 and int(MouseButton.Left) == 1
+assert [0, 1, 2, 3][MouseButton.Middle] == 3
+assert {0x61: "a"}[Key.A] == "a" and Key.A in {0x61}
+assert Key.A != "A" and Key.A != None and Key.Unknown != -1
+button = Event.button_press(Key.A).press_args()
+assert button.value() == Key.A and button.value() in (Key.A, Key.B)
+
+pad = ControllerButton(0, 3)
+assert (pad.id, pad.button) == (0, 3)
+assert pad == ControllerButton(0, 3) and pad != ControllerButton(1, 3)
+assert hash(pad) == hash(ControllerButton(0, 3))
+assert repr(pad) == "ControllerButton(id=0, button=3)"
+
+print("ok")
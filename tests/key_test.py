@@ -10,11 +10,11 @@ keys = []
 
 while event := events.next(window):
     if button := event.press_args():
-        keys.append(button.value())
+        keys.append(button)
     
     if button := event.release_args():
         try:
-            keys.remove(button.value())
+            keys.remove(button)
         except:
             pass
 
@@ -0,0 +1,74 @@
+from piston2d.input import Key
+from piston2d.opengl import GlGraphics
+from piston2d.window import Window, WindowSettings
+from piston2d.window.events import (Event, Events, EventSettings, KeyPress,
+                                    MultiEvents, Render, Update)
+
+editor = Window(WindowSettings("editor", (64, 64), headless=True))
+preview = Window(WindowSettings("preview", (32, 32), headless=True))
+assert editor.id != preview.id
+assert editor.duplicate().id not in (editor.id, preview.id)
+
+# Created events are not from a window until one receives them
+event = Event.key_press(Key.A)
+assert event.window_id is None
+preview.push_event(event)
+assert preview.wait_event_timeout(0).window_id == preview.id
+
+# Events from a single window loop carry its id
+events = Events(EventSettings(max_fps=1000, ups=1000), editor)
+assert next(events).window_id == editor.id
+
+# Several windows can be driven from one loop
+settings = EventSettings(max_fps=1000, ups=1000)
+events = MultiEvents(settings, [editor, preview])
+assert [window.id for window in events.windows] == [editor.id, preview.id]
+assert events.settings.max_fps == 1000
+
+preview.push_event(Event.key_press(Key.P))
+renders = {editor.id: 0, preview.id: 0}
+updates = {editor.id: 0, preview.id: 0}
+pressed = []
+for count, event in enumerate(events):
+    if isinstance(event, Render):
+        renders[event.window_id] += 1
+    elif isinstance(event, Update):
+        updates[event.window_id] += 1
+    elif isinstance(event, KeyPress):
+        pressed.append((event.window_id, event.key))
+    if count == 400:
+        break
+
+assert all(renders.values()) and all(updates.values()), (renders, updates)
+assert pressed == [(preview.id, Key.P)]
+
+# Windows stop being run once their loop ends
+editor.should_close = True
+ids = {event.window_id for _, event in zip(range(100), events)}
+assert ids == {preview.id}, ids
+assert [window.id for window in events.windows] == [preview.id]
+
+assert not events.remove(editor)
+assert events.remove(preview)
+assert events.next() is None
+
+events.add(preview)
+try:
+    events.add(preview)
+    raise AssertionError("ran the same window twice")
+except ValueError as e:
+    print(e)
+
+try:
+    MultiEvents(EventSettings(lazy=True))
+    raise AssertionError("lazy events for several windows")
+except ValueError as e:
+    print(e)
+
+try:
+    GlGraphics("3.2", window=preview)
+    raise AssertionError("graphics for a headless window")
+except ValueError as e:
+    print(e)
+
+print("ok")
@@ -37,12 +37,12 @@ blue = change_factory()
 
 while event := events.next(window):
     if button := event.press_args():
-        keys.append(button.value())
+        keys.append(button)
         print("Keys pressed: {}".format(keys))
 
     if button := event.release_args():
         try:
-            keys.remove(button.value())
+            keys.remove(button)
         except:
             pass
 
@@ -0,0 +1,195 @@
+import math
+import os
+import tempfile
+
+from piston2d.graphics import (DrawState, Matrix2d, circle_arc, ellipse, image,
+                               image_src, line, measure_text, polygon, polyline,
+                               rectangle, text)
+from piston2d.software import (SoftwareGlyphCache, SoftwareGraphics,
+                               SoftwareTexture)
+
+graphics = SoftwareGraphics(64, 64)
+
+context = graphics.draw_begin()
+graphics.clear_color([0.0, 0.0, 0.0, 1.0])
+
+# Red square in the top left corner
+rectangle([1.0, 0.0, 0.0, 1.0], [0, 0, 16, 16], context.transform(), graphics)
+
+# Green ring around the centre
+circle_arc([0.0, 1.0, 0.0, 1.0], 2.0, 0.0, math.tau - 0.01,
+           [24, 24, 16, 16], context.transform(), graphics)
+
+graphics.draw_end()
+
+assert graphics.get_pixel(8, 8) == [255, 0, 0, 255]
+assert graphics.get_pixel(40, 40) == [0, 0, 0, 255]
+assert graphics.get_pixel(32, 24) == [0, 255, 0, 255]
+
+# Rows are read back from the top
+pixels = graphics.read_pixels()
+assert len(pixels) == 64 * 64 * 4
+assert pixels[:4] == bytes([255, 0, 0, 255])
+
+path = os.path.join(tempfile.mkdtemp(), "software.png")
+graphics.save_png(path)
+assert os.path.getsize(path) > 0
+
+# Remaining primitives, each over a black background
+context = graphics.draw_begin()
+graphics.clear_color([0.0, 0.0, 0.0, 1.0])
+ellipse([1.0, 1.0, 1.0, 1.0], [0, 0, 16, 8], context.transform(), graphics)
+line([1.0, 0.0, 0.0, 1.0], 1.0, [0, 32, 64, 32], context.transform(), graphics)
+polygon([0.0, 1.0, 0.0, 1.0], [(48, 0), (64, 0), (64, 16)], context.transform(), graphics)
+polyline([0.0, 0.0, 1.0, 1.0], 1.0, [(0, 48), (16, 48), (16, 63)],
+         context.transform(), graphics, shape="square")
+graphics.draw_end()
+
+assert graphics.get_pixel(8, 4) == [255, 255, 255, 255]
+assert graphics.get_pixel(32, 32) == [255, 0, 0, 255]
+assert graphics.get_pixel(62, 4) == [0, 255, 0, 255]
+assert graphics.get_pixel(8, 48) == [0, 0, 255, 255]
+assert graphics.get_pixel(16, 56) == [0, 0, 255, 255]
+
+# A 2x1 texture: blue on the left, white on the right
+texture = SoftwareTexture.from_bytes(bytes([0, 0, 255, 255, 255, 255, 255, 255]), 2, 1)
+assert texture.size == (2, 1)
+
+context = graphics.draw_begin()
+image(texture, context.transform(), graphics)
+image_src(texture, [1, 0, 1, 1], [48, 48, 16, 16], context.transform(), graphics)
+graphics.draw_end()
+
+assert graphics.get_pixel(0, 0) == [0, 0, 255, 255]
+assert graphics.get_pixel(1, 0) == [255, 255, 255, 255]
+assert graphics.get_pixel(56, 56) == [255, 255, 255, 255]
+
+# Matrix2d
+matrix = Matrix2d(context.transform())
+assert matrix == Matrix2d(matrix.to_list())
+assert matrix.invert().invert() == matrix
+assert Matrix2d().trans(2, 3).transform_pos(1, 1) == [3.0, 4.0]
+assert Matrix2d().scale(2, 2) * Matrix2d().trans(1, 1) == Matrix2d().scale(2, 2).trans(1, 1)
+for operands in [(Matrix2d(), 2), (2, Matrix2d()), (Matrix2d(), "x")]:
+    try:
+        operands[0] * operands[1]
+        raise AssertionError("matrix multiplied by a non-matrix")
+    except TypeError as e:
+        assert "unsupported operand" in str(e) or "can't multiply" in str(e), e
+
+
+class Reflected:
+    def __rmul__(self, other):
+        return "reflected"
+
+
+assert Matrix2d() * Reflected() == "reflected"
+try:
+    Matrix2d().scale(0, 0).invert()
+    raise AssertionError("singular matrix inverted")
+except ValueError:
+    pass
+
+context = graphics.draw_begin()
+graphics.clear_color([0.0, 0.0, 0.0, 1.0])
+rectangle([0.0, 1.0, 0.0, 1.0], [0, 0, 8, 8], matrix.trans(32, 32).zoom(2), graphics)
+graphics.draw_end()
+
+assert graphics.get_pixel(40, 40) == [0, 255, 0, 255]
+assert graphics.get_pixel(50, 50) == [0, 0, 0, 255]
+
+# Chained context transforms
+context = graphics.draw_begin()
+graphics.clear_color([0.0, 0.0, 0.0, 1.0])
+moved = context.trans(32, 32)
+assert moved.matrix == context.matrix.trans(32, 32)
+assert moved.reset().matrix == Matrix2d()
+assert moved.store_view().view_transform().matrix == moved.matrix
+assert moved.view_transform().matrix == context.matrix
+rectangle([0.0, 0.0, 1.0, 1.0], [0, 0, 4, 4], moved.zoom(2).transform(), graphics)
+graphics.draw_end()
+
+assert graphics.get_pixel(36, 36) == [0, 0, 255, 255]
+assert graphics.get_pixel(4, 4) == [0, 0, 0, 255]
+
+# Draw states
+WHITE = [1.0, 1.0, 1.0, 1.0]
+assert DrawState() == DrawState.new_alpha()
+assert DrawState().blend_mode == "alpha"
+assert DrawState.new_clip().stencil_mode == "clip"
+assert DrawState.new_clip().stencil_value == 255
+assert DrawState().scissor([1, 2, 3, 4]).scissor_rect == [1, 2, 3, 4]
+assert DrawState().stencil("inside", 3).stencil_value == 3
+assert DrawState().blend(None).blend_mode is None
+assert context.draw_state == DrawState()
+try:
+    DrawState().blend("screen")
+    raise AssertionError("unknown blend mode accepted")
+except ValueError:
+    pass
+
+context = graphics.draw_begin()
+graphics.clear_color([0.0, 0.0, 0.0, 1.0])
+rectangle(WHITE, [0, 0, 64, 64], context.transform(), graphics,
+          draw_state=DrawState().scissor([0, 0, 8, 8]))
+graphics.draw_end()
+
+assert graphics.get_pixel(4, 4) == [255, 255, 255, 255]
+assert graphics.get_pixel(12, 4) == [0, 0, 0, 255]
+
+context = graphics.draw_begin()
+graphics.clear_color([0.0, 0.0, 0.0, 1.0])
+graphics.clear_stencil(0)
+ellipse(WHITE, [16, 16, 32, 32], context.transform(), graphics,
+        draw_state=DrawState.new_clip())
+assert graphics.get_pixel(32, 32) == [0, 0, 0, 255]
+rectangle([1.0, 0.0, 0.0, 1.0], [0, 0, 64, 64], context.transform(), graphics,
+          draw_state=DrawState.new_inside())
+rectangle([0.0, 0.0, 1.0, 1.0], [0, 0, 64, 64], context.transform(), graphics,
+          draw_state=DrawState.new_outside())
+rectangle([0.0, 1.0, 0.0, 1.0], [0, 0, 64, 64], context.transform(), graphics,
+          draw_state=DrawState().blend("add"))
+graphics.draw_end()
+
+assert graphics.get_pixel(32, 32) == [255, 255, 0, 255]
+assert graphics.get_pixel(1, 1) == [0, 255, 255, 255]
+
+# Text, with a system font as none is shipped with the tests
+FONTS = [
+    os.environ.get("PISTON2D_TEST_FONT", ""),
+    "/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf",
+    "/usr/share/fonts/TTF/DejaVuSans.ttf",
+    "/Library/Fonts/Arial.ttf",
+    "C:\\Windows\\Fonts\\arial.ttf",
+]
+font = next((path for path in FONTS if path and os.path.exists(path)), None)
+if font is None:
+    print("No font found, skipping text (set PISTON2D_TEST_FONT)")
+else:
+    cache = SoftwareGlyphCache.from_path(font)
+    width, height = measure_text(24, "H", cache)
+    assert width > 0 and height > 0
+    assert measure_text(24, "HH", cache)[0] > width
+
+    # Text is drawn with its baseline at the transform's origin
+    context = graphics.draw_begin()
+    graphics.clear_color([0.0, 0.0, 0.0, 1.0])
+    text(WHITE, 24, "H", cache, context.trans(4, 40).transform(), graphics)
+    graphics.draw_end()
+
+    lit = [(x, y) for x in range(64) for y in range(64)
+           if graphics.get_pixel(x, y) != [0, 0, 0, 255]]
+    assert lit, "no glyph drawn"
+    xs = [x for x, _ in lit]
+    ys = [y for _, y in lit]
+    assert 4 <= min(xs) and max(xs) <= 4 + width, (min(xs), max(xs), width)
+    assert 40 - height <= min(ys) and max(ys) <= 40, (min(ys), max(ys), height)
+    assert max(xs) - min(xs) >= width / 2
+
+    try:
+        SoftwareGlyphCache.from_bytes(b"not a font")
+        raise AssertionError("invalid font loaded")
+    except ValueError:
+        pass
+
+print("Software backend OK")
@@ -0,0 +1,76 @@
+from piston2d.input import ControllerButton, Key, MouseButton
+from piston2d.window import Window, WindowSettings
+from piston2d.window.events import (Close, Event, EventKind, Events,
+                                    EventSettings, FileDrop, KeyPress,
+                                    KeyRelease, MouseMove, MousePress,
+                                    MouseScroll, Resize, Text, Update)
+
+# Constructed events are the subclass for their kind
+event = Event.key_press(Key.A, 30)
+assert isinstance(event, KeyPress) and event.kind == EventKind.KEY_PRESS
+assert event.key == Key.A and event.scancode == 30
+assert Event.key_release(Key.A.code).key == Key.A
+assert Event.key_press(Event.key_press(Key.B).press_args()).key == Key.B
+assert Event.mouse_press(MouseButton.Left).button == MouseButton.Left
+assert isinstance(Event.mouse_move(1, 2), MouseMove)
+assert Event.mouse_move(1, 2).position == [1.0, 2.0]
+assert Event.update(0.5).dt == 0.5
+assert Event.resize(320, 240).window_size == [320.0, 240.0]
+assert Event.text("hi").text == "hi"
+assert Event.file_drop("/tmp/a.png").path == "/tmp/a.png"
+assert Event.focus(True).focused
+assert isinstance(Event.close(), Close)
+
+press = Event.button_press(ControllerButton(0, 3))
+assert press.kind == EventKind.BUTTON_PRESS
+assert press.press_args().controller == ControllerButton(0, 3)
+
+try:
+    Event.mouse_press(Key.A)
+    raise AssertionError("mouse press from a key")
+except TypeError as e:
+    print(e)
+
+# Pushed events are received before any from the window itself
+window = Window(WindowSettings("synthetic", (64, 64), headless=True))
+pushed = [
+    Event.key_press(Key.Space),
+    Event.mouse_move(20, 40),
+    Event.mouse_scroll(0, -1),
+    Event.resize(128, 96),
+    Event.key_release(Key.Space),
+]
+for event in pushed:
+    window.push_event(event)
+
+assert isinstance(window.wait_event(), KeyPress)
+assert isinstance(window.wait_event_timeout(0), MouseMove)
+
+received = []
+events = Events(EventSettings(max_fps=1000, ups=1000), window)
+for count, event in enumerate(events):
+    match event:
+        case MouseScroll(dx, dy):
+            received.append(("scroll", dx, dy))
+        case Resize([width, height], _):
+            received.append(("resize", width, height))
+        case KeyRelease(key):
+            received.append(("release", key))
+        case Update():
+            pass
+    if count == 100:
+        window.should_close = True
+
+assert received == [
+    ("scroll", 0.0, -1.0),
+    ("resize", 128.0, 96.0),
+    ("release", Key.Space),
+], received
+
+# Lazy events can run on a headless window while there are pushed events
+window = Window(WindowSettings("lazy", (64, 64), headless=True))
+window.push_event(Event.text("a"))
+event = Events(EventSettings(lazy=True)).next(window)
+assert isinstance(event, Text) and event.text == "a"
+
+print("ok")
@@ -0,0 +1,63 @@
+from piston2d.graphics import rectangle
+from piston2d.input import Key
+from piston2d.opengl import GlGraphics
+from piston2d.window import Window, WindowSettings
+from piston2d.window.events import (AfterRender, Event, EventSettings, Idle,
+                                    KeyPress, MultiEvents, Render, Update)
+
+editor = Window(WindowSettings("editor", (320, 240)))
+preview = Window(WindowSettings("preview", (160, 120)))
+assert editor.id != preview.id
+windows = {editor.id: editor, preview.id: preview}
+
+# Each window has its own context, so its own graphics
+graphics = {
+    editor.id: GlGraphics("3.2", window=editor),
+    preview.id: GlGraphics("3.2", window=preview),
+}
+colors = {
+    editor.id: [0.2, 0.2, 0.3, 1.0],
+    preview.id: [0.9, 0.9, 0.9, 1.0],
+}
+
+# Pushed input is received with the id of the window it was pushed to. Input
+# from the display could be for either window, so has no id.
+preview.push_event(Event.key_press(Key.A, 30))
+
+events = MultiEvents(EventSettings(max_fps=1000, ups=1000), [editor, preview])
+rendered = {editor.id: 0, preview.id: 0}
+pressed = []
+for count, event in enumerate(events):
+    match event:
+        case Render():
+            # Render events are for their own window, which is made current
+            window = windows[event.window_id]
+            assert window.is_current()
+            assert event.window_size == [float(n) for n in window.size]
+
+            gl = graphics[event.window_id]
+            context = gl.draw_begin(event.viewport)
+            gl.clear_color(colors[event.window_id])
+            rectangle([1.0, 0.0, 0.0, 1.0], [10, 10, 40, 40],
+                      context.transform(), gl)
+            gl.draw_end()
+            rendered[event.window_id] += 1
+        case KeyPress(Key.A, 30):
+            pressed.append(event.window_id)
+        case AfterRender() | Update() | Idle():
+            assert event.window_id in windows, event
+        case _:
+            assert event.window_id is None, event
+
+    if count == 200:
+        preview.should_close = True
+    if count == 400:
+        break
+
+assert rendered[editor.id] > 0 and rendered[preview.id] > 0, rendered
+assert pressed == [preview.id], pressed
+
+# The closed window left the loop, the other is still run
+assert [window.id for window in events.windows] == [editor.id]
+
+print("ok")
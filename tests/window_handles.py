@@ -0,0 +1,54 @@
+import copy
+
+from piston2d.window import Window, WindowSettings
+from piston2d.window.events import Event, KeyPress
+
+settings = WindowSettings("handles", (64, 32), headless=True)
+window = Window(settings)
+
+# Copies are handles to the same window
+handle = copy.copy(window)
+assert handle is not window
+handle.title = "renamed"
+handle.size = (128, 64)
+assert window.title == "renamed" and window.size == [128, 64]
+
+window.push_event(Event.key_press(0x61))
+assert isinstance(handle.wait_event_timeout(0), KeyPress)
+assert window.wait_event_timeout(0) is None
+
+window.should_close = True
+assert handle.should_close
+window.should_close = False
+
+# Settings follow changes made to the window, without changing the settings
+# it was created from
+window.exit_on_esc = False
+window.automatic_close = False
+current = window.settings
+assert current.title == "renamed" and current.size == [128, 64]
+assert not current.exit_on_esc and not current.automatic_close
+assert current.headless
+assert settings.title == "handles" and settings.size == [64, 32]
+
+current.title = "unused"
+assert window.settings.title == "renamed"
+
+# Headless windows have the last position set on them
+assert window.position is None
+handle.position = (10, 20)
+assert window.position == (10, 20)
+
+# Duplicates are new windows, with the current settings and position
+other = window.duplicate()
+assert other.headless and other.position == (10, 20)
+assert other.title == "renamed" and other.size == [128, 64]
+assert not other.exit_on_esc and not other.automatic_close
+
+other.title = "other"
+other.position = (30, 40)
+assert window.title == "renamed" and window.position == (10, 20)
+window.push_event(Event.update(0.5))
+assert other.wait_event_timeout(0) is None
+
+print("ok")